@@ -0,0 +1,58 @@
+//! Decoders for the accounts owned by both programs. Each one checks the Anchor
+//! discriminator before deserializing.
+
+use anchor_lang::{AccountDeserialize, Result};
+use boomplay_governance::states::{
+    AnswerCommit, AnswerOption, AnswerVote, AnswerVoterRecord, DecisionVote, DecisionVoterRecord, DelegateIndex,
+    Governance, GovernanceConfig, GovernanceItem, PhaseRewardClaim, Proposal, ProposalVoterRecord, QuestVote,
+    QuestVoterRecord, SupplyCheckpoints, VoterCheckpoints, VoterStake,
+};
+use bp_market::states::{
+    AnswerAccount, BettingAccount, ConfigAccount, MarketAccount, MarketSeries, MarketTemplate,
+    ParlayAccount, ParlayPoolAccount, ReferralAccount, ReferralMarketAccount,
+};
+
+/// Decodes any Anchor account from raw account data.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+macro_rules! decoder {
+    ($name:ident => $account:ty) => {
+        pub fn $name(data: &[u8]) -> Result<$account> {
+            decode::<$account>(data)
+        }
+    };
+}
+
+// bp-market
+decoder!(decode_config_account => ConfigAccount);
+decoder!(decode_market_account => MarketAccount);
+decoder!(decode_answer_account => AnswerAccount);
+decoder!(decode_betting_account => BettingAccount);
+decoder!(decode_referral_account => ReferralAccount);
+decoder!(decode_referral_market_account => ReferralMarketAccount);
+decoder!(decode_market_template => MarketTemplate);
+decoder!(decode_market_series => MarketSeries);
+decoder!(decode_parlay_pool_account => ParlayPoolAccount);
+decoder!(decode_parlay_account => ParlayAccount);
+
+// boomplay-governance
+decoder!(decode_governance_config => GovernanceConfig);
+decoder!(decode_governance => Governance);
+decoder!(decode_governance_item => GovernanceItem);
+decoder!(decode_quest_vote => QuestVote);
+decoder!(decode_quest_voter_record => QuestVoterRecord);
+decoder!(decode_decision_vote => DecisionVote);
+decoder!(decode_decision_voter_record => DecisionVoterRecord);
+decoder!(decode_answer_vote => AnswerVote);
+decoder!(decode_answer_option => AnswerOption);
+decoder!(decode_answer_voter_record => AnswerVoterRecord);
+decoder!(decode_answer_commit => AnswerCommit);
+decoder!(decode_phase_reward_claim => PhaseRewardClaim);
+decoder!(decode_voter_checkpoints => VoterCheckpoints);
+decoder!(decode_supply_checkpoints => SupplyCheckpoints);
+decoder!(decode_voter_stake => VoterStake);
+decoder!(decode_delegate_index => DelegateIndex);
+decoder!(decode_proposal => Proposal);
+decoder!(decode_proposal_voter_record => ProposalVoterRecord);
@@ -0,0 +1,221 @@
+//! Instruction builders for every boomplay-governance entrypoint.
+//!
+//! Each builder takes the program's Anchor accounts struct (see [`boomplay_governance::accounts`])
+//! and the instruction arguments. Use [`crate::pda`] to fill in the program-derived addresses.
+
+use boomplay_governance::states::{
+    AnswerTieBreak, DecisionVoteChoice, ProposalResult, QuestVoteChoice, VoteWeighting,
+};
+
+use crate::instruction_builder;
+
+// Admin instructions
+instruction_builder!(boomplay_governance, initialize(Initialize) => Initialize {
+    min_total_vote: u64,
+    max_total_vote: u64,
+    min_required_nft: u8,
+    max_votable_nft: u8,
+    duration_hours: u64,
+    constant_reward_token: u64,
+});
+instruction_builder!(boomplay_governance, create_collection(CreateCollection) => CreateCollection {
+    name: String,
+    symbol: String,
+    uri: String,
+});
+instruction_builder!(boomplay_governance, update_collection(UpdateCollection) => UpdateCollection {
+    name: String,
+    symbol: String,
+    uri: String,
+});
+instruction_builder!(boomplay_governance, pause(Pause) => Pause { paused: bool });
+instruction_builder!(boomplay_governance, withdraw_tokens(WithdrawTokens) => WithdrawTokens { amount: u64 });
+
+// Configuration instructions
+instruction_builder!(boomplay_governance, set_total_vote(SetTotalVote) => SetTotalVote {
+    min_or_max: String,
+    total_vote: u64,
+});
+instruction_builder!(boomplay_governance, set_reward_amount(SetRewardAmount) => SetRewardAmount { reward_amount: u64 });
+instruction_builder!(boomplay_governance, set_min_reward_pool(SetMinRewardPool) => SetMinRewardPool { min_reward_pool: u64 });
+instruction_builder!(boomplay_governance, set_quest_duration_hours(SetQuestDurationHours) => SetQuestDurationHours { hours: u64 });
+instruction_builder!(boomplay_governance, set_max_votes_per_voter(SetMaxVotesPerVoter) => SetMaxVotesPerVoter { max_votes: u8 });
+instruction_builder!(boomplay_governance, set_minimum_required_nfts(SetMinimumRequiredNfts) => SetMinimumRequiredNfts { new_minimum: u8 });
+instruction_builder!(boomplay_governance, update_base_token_mint(UpdateBaseTokenMint) => UpdateBaseTokenMint {});
+instruction_builder!(boomplay_governance, set_vote_thresholds(SetVoteThresholds) => SetVoteThresholds {
+    quorum_bps: u16,
+    approval_bps: u16,
+});
+instruction_builder!(boomplay_governance, set_answer_tie_break(SetAnswerTieBreak) => SetAnswerTieBreak {
+    tie_break: AnswerTieBreak,
+});
+instruction_builder!(boomplay_governance, set_vote_weighting(SetVoteWeighting) => SetVoteWeighting {
+    vote_weighting: VoteWeighting,
+});
+instruction_builder!(boomplay_governance, set_phase_rewards(SetPhaseRewards) => SetPhaseRewards {
+    quest_reward_token: u64,
+    decision_reward_token: u64,
+});
+
+// Account migration instructions
+instruction_builder!(boomplay_governance, migrate_governance_config(MigrateGovernanceConfig) => MigrateGovernanceConfig {});
+instruction_builder!(boomplay_governance, migrate_governance_item(MigrateGovernanceItem) => MigrateGovernanceItem {});
+instruction_builder!(boomplay_governance,
+    /// Append every `pda::proposal_voter` record of the proposal to the returned instruction's
+    /// accounts; their votes rebuild the Yes and No tallies.
+    migrate_proposal(MigrateProposal) => MigrateProposal {}
+);
+
+// Quest management instructions
+instruction_builder!(boomplay_governance, set_quest_result(SetQuestResult) => SetQuestResult { quest_key: u64 });
+instruction_builder!(boomplay_governance, make_quest_result(MakeQuestResult) => MakeQuestResult { quest_key: u64 });
+instruction_builder!(boomplay_governance, cancel_quest(CancelQuest) => CancelQuest { quest_key: u64 });
+instruction_builder!(boomplay_governance, set_quest_end_time(SetQuestEndTime) => SetQuestEndTime {
+    quest_key: u64,
+    new_end_time: i64,
+});
+
+// Decision management instructions
+instruction_builder!(boomplay_governance, set_decision_and_execute_answer(SetDecisionAndExecuteAnswer) => SetDecisionAndExecuteAnswer {
+    quest_key: u64,
+    answer_keys: Vec<u64>,
+});
+instruction_builder!(boomplay_governance, make_decision_and_execute_answer(MakeDecisionAndExecuteAnswer) => MakeDecisionAndExecuteAnswer {
+    quest_key: u64,
+    answer_keys: Vec<u64>,
+});
+instruction_builder!(boomplay_governance, cancel_decision(CancelDecision) => CancelDecision { quest_key: u64 });
+instruction_builder!(boomplay_governance, set_decision_end_time(SetDecisionEndTime) => SetDecisionEndTime {
+    quest_key: u64,
+    new_end_time: i64,
+});
+
+// Answer management instructions
+instruction_builder!(boomplay_governance, set_answer(SetAnswer) => SetAnswer {
+    quest_key: u64,
+    answer_keys: Vec<u64>,
+});
+instruction_builder!(boomplay_governance, cancel_answer(CancelAnswer) => CancelAnswer {
+    quest_key: u64,
+    reason: String,
+});
+instruction_builder!(boomplay_governance, set_answer_end_time(SetAnswerEndTime) => SetAnswerEndTime {
+    quest_key: u64,
+    new_end_time: i64,
+});
+instruction_builder!(boomplay_governance, set_answer_commit_reveal(SetAnswerCommitReveal) => SetAnswerCommitReveal {
+    quest_key: u64,
+    reveal_hours: u64,
+});
+instruction_builder!(boomplay_governance,
+    /// Append the `pda::answer_option` account of every answer key, in `answer_keys` order,
+    /// to the returned instruction's accounts.
+    finalize_answer(FinalizeAnswer) => FinalizeAnswer {
+        quest_key: u64,
+        tie_break_answer: Option<u64>,
+    }
+);
+
+// Reward instructions
+instruction_builder!(boomplay_governance,
+    /// Quests with a funded pool need `reward_pool_token_account` (`pda::reward_pool`) and
+    /// `winning_answer_option` (`pda::answer_option` of the answer result); leave both None otherwise.
+    distribute_dao_reward(DistributeReward) => DistributeDaoReward { quest_key: u64 }
+);
+
+// Proposal instructions
+instruction_builder!(boomplay_governance, set_proposal_result(SetProposalResult) => SetProposalResult {
+    proposal_key: u64,
+});
+
+// User instructions
+instruction_builder!(boomplay_governance, mint_governance_nft(MintGovernanceNft) => MintGovernanceNft {
+    name: String,
+    symbol: String,
+    uri: String,
+});
+instruction_builder!(boomplay_governance, create_governance_item(CreateGovernanceItem) => CreateGovernanceItem {
+    quest_key: u64,
+    question: String,
+});
+instruction_builder!(boomplay_governance, create_proposal(CreateProposal) => CreateProposal {
+    proposal_key: u64,
+    title: String,
+});
+instruction_builder!(boomplay_governance, vote_quest(VoteQuest) => VoteQuest {
+    quest_key: u64,
+    vote_choice: QuestVoteChoice,
+});
+instruction_builder!(boomplay_governance, set_answer_options(SetAnswerOptions) => SetAnswerOptions {
+    quest_key: u64,
+    answer_keys: Vec<u64>,
+});
+instruction_builder!(boomplay_governance, start_decision(StartDecision) => StartDecision { quest_key: u64 });
+instruction_builder!(boomplay_governance,
+    /// To finalize the answer phase, append the `pda::answer_option` account of every answer key,
+    /// in `answer_keys` order, to the returned instruction's accounts.
+    advance_governance_item(AdvanceGovernanceItem) => AdvanceGovernanceItem { quest_key: u64 }
+);
+instruction_builder!(boomplay_governance,
+    /// Pass the voter's record (and the phase's vote account) for each phase to claim; the
+    /// rest may be None. Quests with a reward pool also need `winning_answer_option` and
+    /// `reward_pool_token_account` for the answer reward. `reward_claim` is the voter's
+    /// `pda::phase_reward_claim`, created by the first claim.
+    claim_governance_rewards(ClaimGovernanceRewards) => ClaimGovernanceRewards { quest_key: u64 }
+);
+instruction_builder!(boomplay_governance, fund_reward_pool(FundRewardPool) => FundRewardPool {
+    quest_key: u64,
+    amount: u64,
+});
+instruction_builder!(boomplay_governance,
+    /// `answer_vote` is only needed when the item was cancelled in the answer phase.
+    refund_reward_pool(RefundRewardPool) => RefundRewardPool { quest_key: u64 }
+);
+instruction_builder!(boomplay_governance, vote_decision(VoteDecision) => VoteDecision {
+    quest_key: u64,
+    vote_choice: DecisionVoteChoice,
+});
+instruction_builder!(boomplay_governance, vote_answer(VoteAnswer) => VoteAnswer {
+    quest_key: u64,
+    answer_key: u64,
+});
+instruction_builder!(boomplay_governance, change_quest_vote(ChangeQuestVote) => ChangeQuestVote {
+    quest_key: u64,
+    new_choice: Option<QuestVoteChoice>,
+});
+instruction_builder!(boomplay_governance, change_decision_vote(ChangeDecisionVote) => ChangeDecisionVote {
+    quest_key: u64,
+    new_choice: Option<DecisionVoteChoice>,
+});
+instruction_builder!(boomplay_governance,
+    /// `new_answer_option` is the `pda::answer_option` of `new_answer_key`; leave it None to revoke.
+    change_answer_vote(ChangeAnswerVote) => ChangeAnswerVote {
+        quest_key: u64,
+        new_answer_key: Option<u64>,
+    }
+);
+instruction_builder!(boomplay_governance,
+    /// Build `commitment` with `boomplay_governance::states::answer_commitment` and keep the salt for the reveal.
+    commit_answer_vote(CommitAnswerVote) => CommitAnswerVote {
+        quest_key: u64,
+        commitment: [u8; 32],
+    }
+);
+instruction_builder!(boomplay_governance, reveal_answer_vote(RevealAnswerVote) => RevealAnswerVote {
+    quest_key: u64,
+    answer_key: u64,
+    salt: [u8; 32],
+});
+instruction_builder!(boomplay_governance, vote_proposal(VoteProposal) => VoteProposal {
+    proposal_key: u64,
+    vote_choice: ProposalResult,
+});
+instruction_builder!(boomplay_governance, update_voter_checkpoint(UpdateVoterCheckpoint) => UpdateVoterCheckpoint {});
+instruction_builder!(boomplay_governance, stake_governance_nft(StakeGovernanceNft) => StakeGovernanceNft {});
+instruction_builder!(boomplay_governance, unstake_governance_nft(UnstakeGovernanceNft) => UnstakeGovernanceNft {});
+instruction_builder!(boomplay_governance,
+    /// Both `delegator` and `delegate` must sign the transaction.
+    delegate_votes(DelegateVotes) => DelegateVotes {}
+);
+instruction_builder!(boomplay_governance, undelegate_votes(UndelegateVotes) => UndelegateVotes {});
+instruction_builder!(boomplay_governance, lock_voter_stake(LockVoterStake) => LockVoterStake { locked_until: i64 });
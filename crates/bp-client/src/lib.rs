@@ -0,0 +1,45 @@
+//! Rust client for the bp-market and boomplay-governance programs.
+//!
+//! - [`pda`] derives every program address used by both programs.
+//! - [`market`] and [`governance`] build one instruction per program entrypoint.
+//! - [`accounts`] decodes on-chain accounts.
+//! - [`math`] previews fees and payouts with the same functions the program runs.
+
+pub mod accounts;
+pub mod governance;
+pub mod market;
+pub mod math;
+pub mod pda;
+
+pub use boomplay_governance;
+pub use bp_market;
+
+pub use boomplay_governance::ID as GOVERNANCE_PROGRAM_ID;
+pub use bp_market::ID as MARKET_PROGRAM_ID;
+
+/// Builds an instruction builder function for a program entrypoint from its Anchor
+/// accounts struct and instruction arguments.
+macro_rules! instruction_builder {
+    (
+        $program:ident,
+        $(#[$doc:meta])*
+        $name:ident($accounts:ident) => $data:ident { $($arg:ident: $ty:ty),* $(,)? }
+    ) => {
+        $(#[$doc])*
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name(
+            accounts: $program::accounts::$accounts,
+            $($arg: $ty,)*
+        ) -> anchor_lang::solana_program::instruction::Instruction {
+            anchor_lang::solana_program::instruction::Instruction {
+                program_id: $program::ID,
+                accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+                data: anchor_lang::InstructionData::data(&$program::instruction::$data {
+                    $($arg,)*
+                }),
+            }
+        }
+    };
+}
+
+pub(crate) use instruction_builder;
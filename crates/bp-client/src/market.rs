@@ -0,0 +1,103 @@
+//! Instruction builders for every bp-market entrypoint.
+//!
+//! Each builder takes the program's Anchor accounts struct (see [`bp_market::accounts`]) and the
+//! instruction arguments. Use [`crate::pda`] to fill in the program-derived addresses.
+
+use anchor_lang::prelude::Pubkey;
+use bp_market::helper::MarketParams;
+use bp_market::instructions::AccountType;
+use bp_market::states::FeeDiscountTier;
+
+use crate::instruction_builder;
+
+// Owner instructions
+instruction_builder!(bp_market, initialize(Initialize) => Initialize {
+    base_token: Pubkey,
+    cojam_fee_account: Pubkey,
+    charity_fee_account: Pubkey,
+    remain_account: Pubkey,
+});
+instruction_builder!(bp_market, publish_market(PublishMarket) => PublishMarket {
+    market_key: u64,
+    creator: Pubkey,
+    title: String,
+    betting_token: Pubkey,
+    create_fee: u64,
+    creator_fee_percentage: u64,
+    service_fee_percentage: u64,
+    charity_fee_percentage: u64,
+    answer_keys: Vec<u64>,
+});
+instruction_builder!(bp_market, update_owner(UpdateOwner) => UpdateOwner { new_owner: Pubkey });
+instruction_builder!(bp_market, success_market(SuccessMarket) => SuccessMarket { correct_answer_key: u64 });
+instruction_builder!(bp_market, adjourn_market(AdjournMarket) => AdjournMarket {});
+instruction_builder!(bp_market, finish_market(FinishMarket) => FinishMarket {});
+instruction_builder!(bp_market, lock_user(LockUser) => LockUser { user_to_lock: Pubkey });
+instruction_builder!(bp_market, unlock_user(UnlockUser) => UnlockUser { user_to_unlock: Pubkey });
+instruction_builder!(bp_market, set_account(SetAccount) => SetAccount {
+    account_type: AccountType,
+    new_account: Pubkey,
+});
+instruction_builder!(bp_market, set_base_token(SetBaseToken) => SetBaseToken { new_base_token: Pubkey });
+instruction_builder!(bp_market, set_referral_fee(SetReferralFee) => SetReferralFee { referral_fee_percentage: u64 });
+instruction_builder!(bp_market, set_fee_discount_tiers(SetFeeDiscountTiers) => SetFeeDiscountTiers {
+    tiers: Vec<FeeDiscountTier>,
+});
+instruction_builder!(bp_market, retrieve_tokens(RetrieveTokens) => RetrieveTokens {});
+instruction_builder!(bp_market, pause(Pause) => Pause { paused: bool });
+instruction_builder!(bp_market, freeze_market(FreezeMarket) => FreezeMarket { frozen: bool });
+instruction_builder!(bp_market, void_market(VoidMarket) => VoidMarket { reason: u8 });
+instruction_builder!(bp_market, set_resolution_deadline(SetResolutionDeadline) => SetResolutionDeadline {
+    resolution_deadline: u64,
+});
+instruction_builder!(bp_market, set_min_parlay_outcome_stake(SetMinParlayOutcomeStake) => SetMinParlayOutcomeStake {
+    min_parlay_outcome_stake: u64,
+});
+instruction_builder!(bp_market, migrate_config(MigrateConfig) => MigrateConfig {});
+instruction_builder!(bp_market, migrate_market(MigrateMarket) => MigrateMarket {});
+instruction_builder!(bp_market, create_market_template(CreateMarketTemplate) => CreateMarketTemplate {
+    template_key: u64,
+    params: MarketParams,
+    duration: u64,
+});
+instruction_builder!(bp_market, create_market_series(CreateMarketSeries) => CreateMarketSeries {
+    series_key: u64,
+    start_time: u64,
+    interval: u64,
+    max_epochs: u64,
+});
+instruction_builder!(bp_market, set_market_series_active(SetMarketSeriesActive) => SetMarketSeriesActive {
+    active: bool,
+});
+
+// Permissionless cranks
+instruction_builder!(bp_market, crank_market_series(CrankMarketSeries) => CrankMarketSeries { epoch: u64 });
+instruction_builder!(bp_market, finish_series_market(FinishSeriesMarket) => FinishSeriesMarket {});
+
+// User instructions
+instruction_builder!(bp_market, bet(Bet) => Bet {
+    answer_key: u64,
+    amount: u64,
+    referrer: Option<Pubkey>,
+});
+instruction_builder!(bp_market, receive_token(ReceiveToken) => ReceiveToken {});
+instruction_builder!(bp_market, force_adjourn_market(ForceAdjournMarket) => ForceAdjournMarket {});
+instruction_builder!(bp_market, claim_referral_reward(ClaimReferralReward) => ClaimReferralReward {});
+instruction_builder!(bp_market, seed_liquidity(SeedLiquidity) => SeedLiquidity { amount: u64 });
+instruction_builder!(bp_market, refund_seed_liquidity(RefundSeedLiquidity) => RefundSeedLiquidity {});
+instruction_builder!(bp_market,
+    /// Pass `(market, answer)` account pairs for each leg in `remaining_accounts` order by
+    /// appending them to the returned instruction's accounts.
+    place_parlay(PlaceParlay) => PlaceParlay {
+        parlay_key: u64,
+        pool_id: [u8; 32],
+        market_keys: Vec<u64>,
+        answer_keys: Vec<u64>,
+        amount: u64,
+    }
+);
+instruction_builder!(bp_market,
+    /// The first settlement of a pool needs every leg's market account appended to the
+    /// returned instruction's accounts, in `market_keys` order.
+    settle_parlay(SettleParlay) => SettleParlay {}
+);
@@ -0,0 +1,149 @@
+//! Fee and payout previews. These reuse the program's own math from `bp_market::utils`,
+//! so a preview matches what `success_market`, `receive_token` and `claim_referral_reward` pay.
+
+use anchor_lang::Result;
+use bp_market::error::ProgramErrorCode;
+use bp_market::states::{
+    AnswerAccount, BettingAccount, MarketAccount, MarketStatus, ReferralMarketAccount,
+};
+
+pub use bp_market::utils::{
+    calculate_fee, calculate_market_fees, fee_discount_for, pro_rata_share, MarketFees,
+};
+
+/// Fees `success_market` would take from a finished market, and the market as it would be
+/// stored afterwards.
+pub fn preview_market_fees(market_account: &MarketAccount) -> Result<(MarketFees, MarketAccount)> {
+    let mut resolved = market_account.clone();
+    let fees = calculate_market_fees(&mut resolved)?;
+    Ok((fees, resolved))
+}
+
+/// Tokens `receive_token` would pay for a bet, excluding the fee rebate.
+pub fn bet_payout(
+    market_account: &MarketAccount,
+    answer_account: &AnswerAccount,
+    betting_account: &BettingAccount,
+) -> Result<u64> {
+    match market_account.status {
+        MarketStatus::Success => {
+            if betting_account.answer_key != market_account.correct_answer_key {
+                return Ok(0);
+            }
+
+            let correct_answer_total_tokens = answer_account
+                .answers
+                .iter()
+                .find(|answer| answer.answer_key == market_account.correct_answer_key)
+                .map(|answer| answer.answer_total_tokens)
+                .unwrap_or(0);
+
+            pro_rata_share(
+                market_account.market_reward_base_tokens,
+                market_account.market_remain_tokens,
+                betting_account.tokens as u128,
+                market_account.market_claimed_winning_tokens as u128,
+                correct_answer_total_tokens as u128,
+            )
+            .ok_or(ProgramErrorCode::MathOperationError.into())
+        }
+        MarketStatus::Adjourn | MarketStatus::Voided => Ok(betting_account.tokens),
+        _ => Ok(0),
+    }
+}
+
+/// Service-fee rebate `receive_token` would pay a governance NFT holder's bet.
+pub fn bet_fee_rebate(
+    market_account: &MarketAccount,
+    betting_account: &BettingAccount,
+) -> Result<u64> {
+    if market_account.status != MarketStatus::Success {
+        return Ok(0);
+    }
+
+    pro_rata_share(
+        market_account.fee_discount_tokens,
+        market_account.fee_discount_remain_tokens,
+        betting_account.fee_discount_weight,
+        market_account.market_claimed_fee_discount_weight,
+        market_account.market_fee_discount_weight,
+    )
+    .ok_or(ProgramErrorCode::MathOperationError.into())
+}
+
+/// Reward `claim_referral_reward` would pay a referrer for a market.
+pub fn referral_reward(
+    market_account: &MarketAccount,
+    referral_market_account: &ReferralMarketAccount,
+) -> Result<u64> {
+    if market_account.status != MarketStatus::Success || referral_market_account.claimed {
+        return Ok(0);
+    }
+
+    pro_rata_share(
+        market_account.referral_fee,
+        market_account.referral_remain_tokens,
+        referral_market_account.referred_tokens as u128,
+        market_account.market_claimed_referred_tokens as u128,
+        market_account.market_referred_tokens as u128,
+    )
+    .ok_or(ProgramErrorCode::MathOperationError.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use bp_market::states::MarketAccountV0;
+
+    fn finished_market(total_tokens: u64) -> MarketAccount {
+        MarketAccount::from(MarketAccountV0 {
+            bump: 255,
+            creator: Pubkey::new_unique(),
+            market_key: 1,
+            betting_token: Pubkey::new_unique(),
+            title: "market".to_string(),
+            status: MarketStatus::Finished,
+            creator_fee: 0,
+            creator_fee_percentage: 100,
+            service_fee_percentage: 200,
+            charity_fee_percentage: 50,
+            approve_time: 0,
+            finish_time: 0,
+            adjourn_time: 0,
+            success_time: 0,
+            correct_answer_key: 0,
+            market_total_tokens: total_tokens,
+            market_remain_tokens: total_tokens,
+            market_reward_base_tokens: 0,
+        })
+    }
+
+    #[test]
+    fn test_preview_market_fees_accounts_for_whole_pool() {
+        let market = finished_market(1_000_003);
+        let (fees, resolved) = preview_market_fees(&market).unwrap();
+
+        assert_eq!(market.market_remain_tokens, 1_000_003);
+        assert_eq!(
+            fees.creator_fee + fees.service_fee + fees.charity_fee + resolved.market_reward_base_tokens,
+            1_000_003
+        );
+    }
+
+    #[test]
+    fn test_preview_market_fees_adds_seed_liquidity_without_fees() {
+        let (unseeded_fees, unseeded) = preview_market_fees(&finished_market(1_000_003)).unwrap();
+
+        let mut market = finished_market(1_000_003);
+        market.seed_liquidity_tokens = 5_000;
+        let (fees, resolved) = preview_market_fees(&market).unwrap();
+
+        assert_eq!(fees, unseeded_fees);
+        assert_eq!(
+            resolved.market_reward_base_tokens,
+            unseeded.market_reward_base_tokens + 5_000
+        );
+        assert_eq!(resolved.market_remain_tokens, resolved.market_reward_base_tokens);
+    }
+}
@@ -0,0 +1,294 @@
+//! Program-derived addresses for both programs. Every function returns `(address, bump)`.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use boomplay_governance::constant::{
+    ANSWER_VOTE_SEED, DECISION_VOTER_SEED, DECISION_VOTE_SEED, GOVERNANCE_CONFIG_SEED,
+    GOVERNANCE_ITEM_SEED, GOVERNANCE_SEED, QUEST_VOTER_SEED, QUEST_VOTE_SEED,
+    TREASURY_SEED, TREASURY_TOKEN_ACCOUNT_SEED, VOTER_STAKE_SEED, DELEGATE_INDEX_SEED,
+    PROPOSAL_VOTER_SEED, SUPPLY_CHECKPOINTS_SEED, ANSWER_COMMIT_SEED, REWARD_POOL_SEED,
+    PHASE_REWARD_CLAIM_SEED,
+};
+use boomplay_governance::instructions::PROPOSAL_SEED;
+use bp_market::states::{
+    ANSWER_SEED, BETTING_SEED, CONFIG_SEED, MARKET_SEED, MARKET_SERIES_SEED,
+    MARKET_TEMPLATE_SEED, PARLAY_POOL_SEED, PARLAY_SEED, REFERRAL_MARKET_SEED, REFERRAL_SEED,
+};
+use bp_market::utils::{parlay_pool_id, series_market_key};
+
+// ========================================
+// bp-market
+// ========================================
+
+pub fn market_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED.as_bytes()], &bp_market::ID)
+}
+
+pub fn market(market_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_SEED.as_bytes(), &market_key.to_le_bytes()],
+        &bp_market::ID,
+    )
+}
+
+pub fn answer(market_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ANSWER_SEED.as_bytes(), &market_key.to_le_bytes()],
+        &bp_market::ID,
+    )
+}
+
+pub fn betting(voter: &Pubkey, market_key: u64, answer_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BETTING_SEED.as_bytes(),
+            voter.as_ref(),
+            &market_key.to_le_bytes(),
+            &answer_key.to_le_bytes(),
+        ],
+        &bp_market::ID,
+    )
+}
+
+pub fn referral(referrer: &Pubkey, bet_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REFERRAL_SEED.as_bytes(), referrer.as_ref(), bet_mint.as_ref()],
+        &bp_market::ID,
+    )
+}
+
+pub fn referral_market(referrer: &Pubkey, market_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REFERRAL_MARKET_SEED.as_bytes(),
+            referrer.as_ref(),
+            &market_key.to_le_bytes(),
+        ],
+        &bp_market::ID,
+    )
+}
+
+/// The market's token vault is the associated token account of the market PDA.
+pub fn market_vault(market_key: u64, bet_mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&market(market_key).0, bet_mint)
+}
+
+pub fn market_template(template_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_TEMPLATE_SEED.as_bytes(), &template_key.to_le_bytes()],
+        &bp_market::ID,
+    )
+}
+
+pub fn market_series(series_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_SERIES_SEED.as_bytes(), &series_key.to_le_bytes()],
+        &bp_market::ID,
+    )
+}
+
+/// Market opened by `crank_market_series` for `epoch` of a series.
+pub fn series_market(series_key: u64, epoch: u64) -> (Pubkey, u8) {
+    market(series_market_key(series_key, epoch))
+}
+
+/// Pool shared by every parlay on `market_keys`, which must be in ascending order.
+pub fn parlay_pool(market_keys: &[u64]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PARLAY_POOL_SEED.as_bytes(), &parlay_pool_id(market_keys)],
+        &bp_market::ID,
+    )
+}
+
+pub fn parlay(voter: &Pubkey, parlay_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PARLAY_SEED.as_bytes(), voter.as_ref(), &parlay_key.to_le_bytes()],
+        &bp_market::ID,
+    )
+}
+
+/// The parlay pool's token vault is the associated token account of the pool PDA.
+pub fn parlay_vault(market_keys: &[u64], bet_mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&parlay_pool(market_keys).0, bet_mint)
+}
+
+// ========================================
+// boomplay-governance
+// ========================================
+
+pub fn governance_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GOVERNANCE_CONFIG_SEED], &boomplay_governance::ID)
+}
+
+pub fn governance() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GOVERNANCE_SEED], &boomplay_governance::ID)
+}
+
+pub fn treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED], &boomplay_governance::ID)
+}
+
+pub fn treasury_token_account() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TREASURY_SEED, TREASURY_TOKEN_ACCOUNT_SEED],
+        &boomplay_governance::ID,
+    )
+}
+
+/// Treasury token account created by `update_base_token_mint` for a new base token.
+pub fn treasury_token_account_for_mint(base_token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TREASURY_SEED, TREASURY_TOKEN_ACCOUNT_SEED, base_token_mint.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn governance_item(quest_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[GOVERNANCE_ITEM_SEED, &quest_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn quest_vote(quest_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[QUEST_VOTE_SEED, &quest_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn quest_voter(quest_key: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[QUEST_VOTER_SEED, &quest_key.to_le_bytes(), voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn decision_vote(quest_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DECISION_VOTE_SEED, &quest_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn decision_voter(quest_key: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DECISION_VOTER_SEED, &quest_key.to_le_bytes(), voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn answer_vote(quest_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ANSWER_VOTE_SEED, &quest_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn answer_option(quest_key: u64, answer_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"answer_option", &quest_key.to_le_bytes(), &answer_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn answer_commit(quest_key: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ANSWER_COMMIT_SEED, &quest_key.to_le_bytes(), voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn reward_pool(quest_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REWARD_POOL_SEED, &quest_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn phase_reward_claim(quest_key: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PHASE_REWARD_CLAIM_SEED, &quest_key.to_le_bytes(), voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn answer_voter(quest_key: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"answer_voter", &quest_key.to_le_bytes(), voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn voter_checkpoints(voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"voter_checkpoints", voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn supply_checkpoints() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SUPPLY_CHECKPOINTS_SEED], &boomplay_governance::ID)
+}
+
+pub fn voter_stake(voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTER_STAKE_SEED, voter.as_ref()], &boomplay_governance::ID)
+}
+
+pub fn delegate_index(delegate: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DELEGATE_INDEX_SEED, delegate.as_ref()], &boomplay_governance::ID)
+}
+
+/// Escrow token account holding a staked governance NFT.
+pub fn nft_escrow(voter: &Pubkey, nft_mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&voter_stake(voter).0, nft_mint)
+}
+
+pub fn proposal(proposal_key: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED, &proposal_key.to_le_bytes()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn proposal_voter(proposal_key: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_VOTER_SEED, &proposal_key.to_le_bytes(), voter.as_ref()],
+        &boomplay_governance::ID,
+    )
+}
+
+pub fn collection_mint() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"collection-mint"], &boomplay_governance::ID)
+}
+
+pub fn collection_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"collection-authority"], &boomplay_governance::ID)
+}
+
+pub fn nft_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nft-authority"], &boomplay_governance::ID)
+}
+
+// ========================================
+// Metaplex token metadata
+// ========================================
+
+pub fn metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"metadata", anchor_spl::metadata::ID.as_ref(), mint.as_ref()],
+        &anchor_spl::metadata::ID,
+    )
+}
+
+pub fn master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            anchor_spl::metadata::ID.as_ref(),
+            mint.as_ref(),
+            b"edition",
+        ],
+        &anchor_spl::metadata::ID,
+    )
+}
@@ -0,0 +1,563 @@
+//! Property-based invariant tests for bp-market accounting.
+//!
+//! Each case publishes a market, places random bets from several users, settles it as a
+//! success or an adjournment, claims in random order, then retrieves what is left once the
+//! claim window has passed. Some cases also send tokens straight to the vault before the
+//! last claim. The market's accounting is checked after every step.
+//!
+//! The compiled program runs in-process on LiteSVM, so build it first with `anchor build`
+//! (the tests load `target/deploy/bp_market.so`). The tests are ignored by default for that
+//! reason; run them with `cargo test -p bp-client --test market_invariants -- --ignored`.
+
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token::spl_token;
+use bp_client::bp_market::accounts as market_accounts;
+use bp_client::bp_market::constant::SUCCESS_MARKET_VALIDITY_DATE;
+use bp_client::bp_market::states::{AnswerAccount, MarketAccount};
+use bp_client::{accounts, market, pda, MARKET_PROGRAM_ID};
+use litesvm::LiteSVM;
+use proptest::prelude::*;
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+const MARKET_KEY: u64 = 1;
+const USERS: usize = 6;
+const MAX_ANSWERS: usize = 4;
+const INITIAL_BALANCE: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone)]
+enum Outcome {
+    Success(usize),
+    Adjourn,
+}
+
+#[derive(Debug, Clone)]
+struct Scenario {
+    answer_count: usize,
+    creator_fee_percentage: u64,
+    service_fee_percentage: u64,
+    charity_fee_percentage: u64,
+    /// (user, answer index, amount)
+    bets: Vec<(usize, usize, u64)>,
+    outcome: Outcome,
+    /// Claim order and whether each (user, answer) position claims before retrieval,
+    /// indexed by `user * MAX_ANSWERS + answer`
+    claim_priority: Vec<u32>,
+    claim_before_retrieve: Vec<bool>,
+    /// (user, amount) sent to the vault outside the market's accounting before the last claim
+    donation: Option<(usize, u64)>,
+}
+
+fn scenario() -> impl Strategy<Value = Scenario> {
+    (2..=MAX_ANSWERS).prop_flat_map(|answer_count| {
+        (
+            (0u64..=1_000, 0u64..=1_000, 0u64..=1_000),
+            prop::collection::vec((0..USERS, 0..answer_count, 1u64..=1_000_000), 1..24),
+            prop_oneof![
+                (0..answer_count).prop_map(Outcome::Success),
+                Just(Outcome::Adjourn),
+            ],
+            prop::collection::vec(any::<u32>(), USERS * MAX_ANSWERS),
+            prop::collection::vec(prop::bool::weighted(0.8), USERS * MAX_ANSWERS),
+            prop::option::of((0..USERS, 1u64..=1_000_000)),
+        )
+            .prop_map(
+                move |(fees, bets, outcome, claim_priority, claim_before_retrieve, donation)| Scenario {
+                    answer_count,
+                    creator_fee_percentage: fees.0,
+                    service_fee_percentage: fees.1,
+                    charity_fee_percentage: fees.2,
+                    bets,
+                    outcome,
+                    claim_priority,
+                    claim_before_retrieve,
+                    donation,
+                },
+            )
+    })
+}
+
+fn answer_key(index: usize) -> u64 {
+    100 + index as u64
+}
+
+struct Harness {
+    svm: LiteSVM,
+    owner: Keypair,
+    users: Vec<Keypair>,
+    mint: Pubkey,
+    creator: Pubkey,
+    cojam: Pubkey,
+    charity: Pubkey,
+    remain: Pubkey,
+    deposits: u64,
+    claimed: u64,
+    /// Owed tokens the market held when they were retrieved
+    retrieved: u64,
+    /// Tokens sent to the vault outside the market's accounting and not yet swept
+    surplus: u64,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        let program_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/deploy/bp_market.so");
+        svm.add_program_from_file(MARKET_PROGRAM_ID, program_path)
+            .expect("bp_market.so not found; run `anchor build` first");
+
+        let owner = Keypair::new();
+        let users: Vec<Keypair> = (0..USERS).map(|_| Keypair::new()).collect();
+        svm.airdrop(&owner.pubkey(), 100_000_000_000).unwrap();
+        for user in &users {
+            svm.airdrop(&user.pubkey(), 10_000_000_000).unwrap();
+        }
+
+        let mut harness = Self {
+            svm,
+            owner,
+            users,
+            mint: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            cojam: Pubkey::new_unique(),
+            charity: Pubkey::new_unique(),
+            remain: Pubkey::new_unique(),
+            deposits: 0,
+            claimed: 0,
+            retrieved: 0,
+            surplus: 0,
+        };
+
+        harness.create_mint();
+        for wallet in [harness.creator, harness.cojam, harness.charity, harness.remain] {
+            harness.create_token_account(&wallet, 0);
+        }
+        let user_wallets: Vec<Pubkey> = harness.users.iter().map(|user| user.pubkey()).collect();
+        for wallet in user_wallets {
+            harness.create_token_account(&wallet, INITIAL_BALANCE);
+        }
+
+        harness
+    }
+
+    fn create_mint(&mut self) {
+        let mint = spl_token::state::Mint {
+            mint_authority: COption::Some(self.owner.pubkey()),
+            supply: INITIAL_BALANCE * USERS as u64,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint::pack(mint, &mut data).unwrap();
+        self.set_token_program_account(self.mint, data);
+    }
+
+    fn create_token_account(&mut self, wallet: &Pubkey, amount: u64) {
+        let account = spl_token::state::Account {
+            mint: self.mint,
+            owner: *wallet,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account::pack(account, &mut data).unwrap();
+        self.set_token_program_account(get_associated_token_address(wallet, &self.mint), data);
+    }
+
+    fn set_token_program_account(&mut self, address: Pubkey, data: Vec<u8>) {
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm
+            .set_account(
+                address,
+                Account { lamports, data, owner: spl_token::ID, executable: false, rent_epoch: 0 },
+            )
+            .unwrap();
+    }
+
+    fn send(&mut self, instruction: Instruction, signer: &Keypair) -> Result<(), String> {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        let result = self
+            .svm
+            .send_transaction(transaction)
+            .map(|_| ())
+            .map_err(|failed| format!("{:?}", failed.err));
+        // A retried instruction must fail on program state, not as a duplicate transaction
+        self.svm.expire_blockhash();
+        result
+    }
+
+    fn send_as_owner(&mut self, instruction: Instruction) -> Result<(), String> {
+        let owner = self.owner.insecure_clone();
+        self.send(instruction, &owner)
+    }
+
+    fn advance_time(&mut self, seconds: u64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds as i64;
+        self.svm.set_sysvar::<Clock>(&clock);
+    }
+
+    // Reads
+
+    fn market(&self) -> MarketAccount {
+        let account = self.svm.get_account(&pda::market(MARKET_KEY).0).unwrap();
+        accounts::decode_market_account(&account.data).unwrap()
+    }
+
+    fn answers(&self) -> AnswerAccount {
+        let account = self.svm.get_account(&pda::answer(MARKET_KEY).0).unwrap();
+        accounts::decode_answer_account(&account.data).unwrap()
+    }
+
+    fn bet_tokens(&self, user: usize, answer_key: u64) -> Option<u64> {
+        let address = pda::betting(&self.users[user].pubkey(), MARKET_KEY, answer_key).0;
+        let account = self.svm.get_account(&address).filter(|account| !account.data.is_empty())?;
+        Some(accounts::decode_betting_account(&account.data).ok()?.tokens)
+    }
+
+    fn token_balance(&self, address: &Pubkey) -> u64 {
+        self.svm
+            .get_account(address)
+            .map(|account| spl_token::state::Account::unpack(&account.data).unwrap().amount)
+            .unwrap_or(0)
+    }
+
+    fn wallet_balance(&self, wallet: &Pubkey) -> u64 {
+        self.token_balance(&get_associated_token_address(wallet, &self.mint))
+    }
+
+    fn vault_balance(&self) -> u64 {
+        self.token_balance(&pda::market_vault(MARKET_KEY, &self.mint))
+    }
+
+    fn fees_paid(&self) -> u64 {
+        self.wallet_balance(&self.creator)
+            + self.wallet_balance(&self.cojam)
+            + self.wallet_balance(&self.charity)
+    }
+
+    // Instructions
+
+    fn initialize(&mut self) -> Result<(), String> {
+        let instruction = market::initialize(
+            market_accounts::Initialize {
+                owner: self.owner.pubkey(),
+                config_account: pda::market_config().0,
+                system_program: system_program::ID,
+            },
+            self.mint,
+            self.cojam,
+            self.charity,
+            self.remain,
+        );
+        self.send_as_owner(instruction)
+    }
+
+    fn publish_market(&mut self, scenario: &Scenario) -> Result<(), String> {
+        let instruction = market::publish_market(
+            market_accounts::PublishMarket {
+                owner: self.owner.pubkey(),
+                config_account: pda::market_config().0,
+                market_account: pda::market(MARKET_KEY).0,
+                answer_account: pda::answer(MARKET_KEY).0,
+                system_program: system_program::ID,
+            },
+            MARKET_KEY,
+            self.creator,
+            "invariants".to_string(),
+            self.mint,
+            // publish_market does not collect the creation fee, so it stays zero here
+            0,
+            scenario.creator_fee_percentage,
+            scenario.service_fee_percentage,
+            scenario.charity_fee_percentage,
+            (0..scenario.answer_count).map(answer_key).collect(),
+        );
+        self.send_as_owner(instruction)
+    }
+
+    fn bet(&mut self, user: usize, answer_key: u64, amount: u64) -> Result<(), String> {
+        let voter = self.users[user].insecure_clone();
+        let instruction = market::bet(
+            market_accounts::Bet {
+                voter: voter.pubkey(),
+                config_account: pda::market_config().0,
+                market_account: pda::market(MARKET_KEY).0,
+                bet_mint: self.mint,
+                user_token_account: get_associated_token_address(&voter.pubkey(), &self.mint),
+                vault_token_account: pda::market_vault(MARKET_KEY, &self.mint),
+                answer_account: pda::answer(MARKET_KEY).0,
+                bet_account: pda::betting(&voter.pubkey(), MARKET_KEY, answer_key).0,
+                referral_account: None,
+                referral_market_account: None,
+                voter_stake: None,
+                governance_program: None,
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            answer_key,
+            amount,
+            None,
+        );
+        self.send(instruction, &voter)
+    }
+
+    fn finish_market(&mut self) -> Result<(), String> {
+        let instruction = market::finish_market(market_accounts::FinishMarket {
+            owner: self.owner.pubkey(),
+            config_account: pda::market_config().0,
+            market_account: pda::market(MARKET_KEY).0,
+            system_program: system_program::ID,
+        });
+        self.send_as_owner(instruction)
+    }
+
+    fn success_market(&mut self, correct_answer_key: u64) -> Result<(), String> {
+        let instruction = market::success_market(
+            market_accounts::SuccessMarket {
+                owner: self.owner.pubkey(),
+                config_account: pda::market_config().0,
+                market_account: pda::market(MARKET_KEY).0,
+                bet_mint: self.mint,
+                creator_token_account: get_associated_token_address(&self.creator, &self.mint),
+                cojam_token_account: get_associated_token_address(&self.cojam, &self.mint),
+                charity_token_account: get_associated_token_address(&self.charity, &self.mint),
+                vault_token_account: pda::market_vault(MARKET_KEY, &self.mint),
+                answer_account: pda::answer(MARKET_KEY).0,
+                token_program: spl_token::ID,
+            },
+            correct_answer_key,
+        );
+        self.send_as_owner(instruction)
+    }
+
+    fn adjourn_market(&mut self) -> Result<(), String> {
+        let instruction = market::adjourn_market(market_accounts::AdjournMarket {
+            owner: self.owner.pubkey(),
+            config_account: pda::market_config().0,
+            market_account: pda::market(MARKET_KEY).0,
+            system_program: system_program::ID,
+        });
+        self.send_as_owner(instruction)
+    }
+
+    fn receive_token(&mut self, user: usize, answer_key: u64) -> Result<(), String> {
+        let voter = self.users[user].insecure_clone();
+        let instruction = market::receive_token(market_accounts::ReceiveToken {
+            voter: voter.pubkey(),
+            config_account: pda::market_config().0,
+            market_account: pda::market(MARKET_KEY).0,
+            bet_mint: self.mint,
+            user_bet_token_account: get_associated_token_address(&voter.pubkey(), &self.mint),
+            vault_bet_token_account: pda::market_vault(MARKET_KEY, &self.mint),
+            bet_account: pda::betting(&voter.pubkey(), MARKET_KEY, answer_key).0,
+            answer_account: pda::answer(MARKET_KEY).0,
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        });
+        self.send(instruction, &voter)
+    }
+
+    fn donate(&mut self, user: usize, amount: u64) -> Result<(), String> {
+        let donor = self.users[user].insecure_clone();
+        let instruction = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &get_associated_token_address(&donor.pubkey(), &self.mint),
+            &pda::market_vault(MARKET_KEY, &self.mint),
+            &donor.pubkey(),
+            &[],
+            amount,
+        )
+        .map_err(|error| error.to_string())?;
+        self.send(instruction, &donor)
+    }
+
+    fn retrieve_tokens(&mut self) -> Result<(), String> {
+        let instruction = market::retrieve_tokens(market_accounts::RetrieveTokens {
+            owner: self.owner.pubkey(),
+            config_account: pda::market_config().0,
+            vault_token_account: pda::market_vault(MARKET_KEY, &self.mint),
+            remains_token_account: get_associated_token_address(&self.remain, &self.mint),
+            market_account: pda::market(MARKET_KEY).0,
+            token_program: spl_token::ID,
+            associate_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        });
+        self.send_as_owner(instruction)
+    }
+
+    /// Claims one position, checks the payout against the market state and that a second
+    /// attempt is rejected. Returns the amount paid out.
+    fn claim(&mut self, user: usize, answer_key: u64) -> Result<u64, TestCaseError> {
+        let stake = self.bet_tokens(user, answer_key);
+        let wallet = self.users[user].pubkey();
+        let balance_before = self.wallet_balance(&wallet);
+
+        let result = self.receive_token(user, answer_key);
+        let paid = self.wallet_balance(&wallet) - balance_before;
+
+        if result.is_ok() {
+            prop_assert!(stake.is_some(), "claim succeeded without a bet account");
+            prop_assert!(
+                self.receive_token(user, answer_key).is_err(),
+                "user {} claimed answer {} twice",
+                user,
+                answer_key
+            );
+        } else {
+            prop_assert_eq!(paid, 0, "failed claim moved tokens");
+        }
+
+        self.claimed += paid;
+        Ok(paid)
+    }
+
+    fn check_invariants(&self) -> Result<(), TestCaseError> {
+        let market = self.market();
+        let answers = self.answers();
+        let vault = self.vault_balance();
+
+        let answer_total: u64 = answers.answers.iter().map(|answer| answer.answer_total_tokens).sum();
+        prop_assert_eq!(answer_total, market.market_total_tokens, "answer totals diverged from market total");
+        prop_assert_eq!(market.market_total_tokens, self.deposits, "market total diverged from deposits");
+
+        let owed = market.market_remain_tokens as u128
+            + market.referral_remain_tokens as u128
+            + market.fee_discount_remain_tokens as u128;
+        prop_assert!(
+            vault as u128 >= owed,
+            "vault {} cannot cover outstanding claims {}",
+            vault,
+            owed
+        );
+
+        // Conservation is proven on the counters, so a donation to the vault cannot hide a
+        // shortfall; it only ever shows up as surplus on top of what the market owes
+        let paid_out = self.fees_paid() + self.claimed + self.retrieved;
+        prop_assert!(paid_out <= self.deposits, "paid {} out of {} deposited", paid_out, self.deposits);
+        prop_assert_eq!(
+            paid_out as u128 + owed,
+            self.deposits as u128,
+            "payouts and fees diverged from deposits"
+        );
+        prop_assert_eq!(vault as u128, owed + self.surplus as u128, "tokens left the vault unaccounted");
+
+        Ok(())
+    }
+}
+
+fn run(scenario: Scenario) -> Result<(), TestCaseError> {
+    let mut harness = Harness::new();
+    harness.initialize().map_err(TestCaseError::fail)?;
+    harness.publish_market(&scenario).map_err(TestCaseError::fail)?;
+    harness.check_invariants()?;
+
+    let mut positions = Vec::new();
+    for &(user, answer, amount) in &scenario.bets {
+        harness.bet(user, answer_key(answer), amount).map_err(TestCaseError::fail)?;
+        harness.deposits += amount;
+        if !positions.contains(&(user, answer)) {
+            positions.push((user, answer));
+        }
+        harness.check_invariants()?;
+    }
+
+    harness.finish_market().map_err(TestCaseError::fail)?;
+    harness.check_invariants()?;
+
+    match scenario.outcome {
+        Outcome::Success(answer) => harness.success_market(answer_key(answer)),
+        Outcome::Adjourn => harness.adjourn_market(),
+    }
+    .map_err(TestCaseError::fail)?;
+    harness.check_invariants()?;
+
+    let reward_base = harness.market().market_reward_base_tokens;
+    positions.sort_by_key(|&(user, answer)| scenario.claim_priority[user * MAX_ANSWERS + answer]);
+    let (early, late): (Vec<_>, Vec<_>) = positions
+        .into_iter()
+        .partition(|&(user, answer)| scenario.claim_before_retrieve[user * MAX_ANSWERS + answer]);
+
+    let mut winnings = 0;
+    for (index, &(user, answer)) in early.iter().enumerate() {
+        if index + 1 == early.len() {
+            donate(&mut harness, scenario.donation)?;
+        }
+        let stake = harness.bet_tokens(user, answer_key(answer)).unwrap_or(0);
+        let paid = harness.claim(user, answer_key(answer))?;
+        match scenario.outcome {
+            Outcome::Adjourn => {
+                prop_assert_eq!(paid, stake, "adjourned market did not refund the stake");
+            }
+            Outcome::Success(correct) if correct != answer => {
+                prop_assert_eq!(paid, 0, "losing position was paid");
+            }
+            Outcome::Success(_) => winnings += paid,
+        }
+        harness.check_invariants()?;
+    }
+    prop_assert!(winnings <= reward_base, "winners received {} of a {} pool", winnings, reward_base);
+
+    if early.is_empty() {
+        donate(&mut harness, scenario.donation)?;
+    }
+
+    harness.advance_time(SUCCESS_MARKET_VALIDITY_DATE + 1);
+    let market = harness.market();
+    let owed = market.market_remain_tokens + market.referral_remain_tokens + market.fee_discount_remain_tokens;
+    harness.retrieve_tokens().map_err(TestCaseError::fail)?;
+    harness.retrieved += owed;
+    prop_assert_eq!(
+        harness.wallet_balance(&harness.remain),
+        harness.retrieved + harness.surplus,
+        "retrieval did not sweep the surplus to the remain account"
+    );
+    harness.surplus = 0;
+    harness.check_invariants()?;
+    prop_assert_eq!(harness.vault_balance(), 0, "tokens stuck in the vault after retrieval");
+
+    for &(user, answer) in &late {
+        let paid = harness.claim(user, answer_key(answer))?;
+        prop_assert_eq!(paid, 0, "claim paid out after retrieval");
+        harness.check_invariants()?;
+    }
+
+    Ok(())
+}
+
+fn donate(harness: &mut Harness, donation: Option<(usize, u64)>) -> Result<(), TestCaseError> {
+    if let Some((user, amount)) = donation {
+        harness.donate(user, amount).map_err(TestCaseError::fail)?;
+        harness.surplus += amount;
+        harness.check_invariants()?;
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    #[ignore = "needs target/deploy/bp_market.so from `anchor build`"]
+    fn market_accounting_invariants_hold(scenario in scenario()) {
+        run(scenario)?;
+    }
+}
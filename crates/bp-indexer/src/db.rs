@@ -0,0 +1,716 @@
+//! SQLite store. Every transaction is applied together with its cursor update, so an
+//! interrupted run resumes from the last fully indexed signature.
+
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::events::{GovernanceEvent, MarketEvent};
+
+/// Program keys use the full u64 range (series market keys set the top bit), so they are
+/// stored as decimal TEXT. Amounts, slots and timestamps are INTEGER, bit-cast from u64 (see `int`).
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS cursors (
+    program         TEXT PRIMARY KEY,
+    last_signature  TEXT NOT NULL,
+    last_slot       INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS events (
+    signature       TEXT NOT NULL,
+    program         TEXT NOT NULL,
+    event_index     INTEGER NOT NULL,
+    name            TEXT NOT NULL,
+    slot            INTEGER NOT NULL,
+    block_time      INTEGER,
+    PRIMARY KEY (signature, program, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS markets (
+    market_key              TEXT PRIMARY KEY,
+    address                 TEXT NOT NULL UNIQUE,
+    creator                 TEXT NOT NULL,
+    betting_token           TEXT NOT NULL,
+    title                   TEXT NOT NULL,
+    status                  TEXT NOT NULL,
+    create_fee              INTEGER NOT NULL,
+    creator_fee_percentage  INTEGER NOT NULL,
+    service_fee_percentage  INTEGER NOT NULL,
+    charity_fee_percentage  INTEGER NOT NULL,
+    referral_fee_percentage INTEGER NOT NULL,
+    total_tokens            INTEGER NOT NULL DEFAULT 0,
+    remain_tokens           INTEGER NOT NULL DEFAULT 0,
+    correct_answer_key      TEXT,
+    creator_fee             INTEGER,
+    service_fee             INTEGER,
+    charity_fee             INTEGER,
+    referral_fee            INTEGER,
+    fee_discount            INTEGER,
+    reward_base_tokens      INTEGER,
+    force_adjourned         INTEGER NOT NULL DEFAULT 0,
+    void_reason             INTEGER,
+    frozen                  INTEGER NOT NULL DEFAULT 0,
+    series_key              TEXT,
+    series_epoch            INTEGER,
+    previous_market_key     TEXT,
+    seed_liquidity_tokens   INTEGER NOT NULL DEFAULT 0,
+    published_slot          INTEGER NOT NULL,
+    updated_slot            INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS answers (
+    market_key      TEXT NOT NULL,
+    answer_key      TEXT NOT NULL,
+    total_tokens    INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (market_key, answer_key)
+);
+
+CREATE TABLE IF NOT EXISTS bets (
+    market_key      TEXT NOT NULL,
+    voter           TEXT NOT NULL,
+    answer_key      TEXT NOT NULL,
+    referrer        TEXT,
+    bet_count       INTEGER NOT NULL,
+    tokens          INTEGER NOT NULL,
+    claimed         INTEGER NOT NULL DEFAULT 0,
+    first_slot      INTEGER NOT NULL,
+    last_slot       INTEGER NOT NULL,
+    PRIMARY KEY (market_key, voter, answer_key)
+);
+
+CREATE TABLE IF NOT EXISTS claims (
+    signature       TEXT NOT NULL,
+    kind            TEXT NOT NULL,
+    market_key      TEXT NOT NULL,
+    claimant        TEXT NOT NULL,
+    answer_key      TEXT,
+    amount          INTEGER NOT NULL,
+    fee_rebate      INTEGER NOT NULL DEFAULT 0,
+    slot            INTEGER NOT NULL,
+    PRIMARY KEY (signature, kind, claimant)
+);
+
+CREATE TABLE IF NOT EXISTS parlays (
+    voter           TEXT NOT NULL,
+    parlay_key      TEXT NOT NULL,
+    pool            TEXT NOT NULL,
+    legs            TEXT NOT NULL,
+    stake           INTEGER NOT NULL,
+    settlement      TEXT,
+    payout          INTEGER,
+    placed_slot     INTEGER NOT NULL,
+    settled_slot    INTEGER,
+    PRIMARY KEY (voter, parlay_key)
+);
+
+CREATE TABLE IF NOT EXISTS governance_items (
+    quest_key               TEXT PRIMARY KEY,
+    question                TEXT NOT NULL,
+    creator                 TEXT NOT NULL,
+    phase                   TEXT NOT NULL,
+    start_slot              INTEGER NOT NULL,
+    quest_start_time        INTEGER NOT NULL,
+    quest_end_time          INTEGER NOT NULL,
+    quest_result            TEXT,
+    decision_start_time     INTEGER,
+    decision_end_time       INTEGER,
+    decision_result         TEXT,
+    answer_start_time       INTEGER,
+    answer_end_time         INTEGER,
+    answer_result           TEXT,
+    updated_slot            INTEGER NOT NULL
+);
+
+-- quest_key holds the proposal_key for phase 'Proposal'
+CREATE TABLE IF NOT EXISTS votes (
+    quest_key       TEXT NOT NULL,
+    phase           TEXT NOT NULL,
+    voter           TEXT NOT NULL,
+    choice          TEXT NOT NULL,
+    votes           INTEGER NOT NULL,
+    signature       TEXT NOT NULL,
+    slot            INTEGER NOT NULL,
+    PRIMARY KEY (quest_key, phase, voter)
+);
+
+CREATE TABLE IF NOT EXISTS rewards (
+    quest_key       TEXT NOT NULL,
+    voter           TEXT NOT NULL,
+    answer_key      TEXT NOT NULL,
+    vote_count      INTEGER NOT NULL,
+    reward_amount   INTEGER NOT NULL,
+    signature       TEXT NOT NULL,
+    slot            INTEGER NOT NULL,
+    PRIMARY KEY (quest_key, voter)
+);
+"#;
+
+/// Where an event came from.
+pub struct TxContext<'a> {
+    pub signature: &'a str,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+}
+
+pub struct Database {
+    conn: Connection,
+}
+
+impl Database {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Last signature indexed for `program`, if any.
+    pub fn cursor(&self, program: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT last_signature FROM cursors WHERE program = ?1",
+                params![program],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    pub fn record_market_transaction(
+        &mut self,
+        program: &str,
+        ctx: &TxContext,
+        events: &[MarketEvent],
+    ) -> Result<()> {
+        self.record_transaction(program, ctx, events, MarketEvent::name, apply_market_event)
+    }
+
+    pub fn record_governance_transaction(
+        &mut self,
+        program: &str,
+        ctx: &TxContext,
+        events: &[GovernanceEvent],
+    ) -> Result<()> {
+        self.record_transaction(program, ctx, events, GovernanceEvent::name, apply_governance_event)
+    }
+
+    fn record_transaction<E>(
+        &mut self,
+        program: &str,
+        ctx: &TxContext,
+        events: &[E],
+        name: fn(&E) -> &'static str,
+        apply: fn(&Transaction, &TxContext, &E) -> Result<()>,
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+
+        for (index, event) in events.iter().enumerate() {
+            // Events already stored were materialized by an earlier run
+            let inserted = tx.execute(
+                "INSERT OR IGNORE INTO events (signature, program, event_index, name, slot, block_time)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    ctx.signature,
+                    program,
+                    index as i64,
+                    name(event),
+                    int(ctx.slot),
+                    ctx.block_time
+                ],
+            )?;
+            if inserted == 1 {
+                apply(&tx, ctx, event)?;
+            }
+        }
+
+        tx.execute(
+            "INSERT INTO cursors (program, last_signature, last_slot) VALUES (?1, ?2, ?3)
+             ON CONFLICT(program) DO UPDATE SET
+                last_signature = excluded.last_signature,
+                last_slot = excluded.last_slot",
+            params![program, ctx.signature, int(ctx.slot)],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// SQLite integers are signed 64-bit, so u64 amounts are stored bit-cast and read back with
+/// `as u64`. Failing on values past `i64::MAX` would stall the sync on that transaction for good.
+fn int(value: u64) -> i64 {
+    value as i64
+}
+
+fn set_market_status(tx: &Transaction, market_key: u64, status: &str, slot: u64) -> Result<()> {
+    tx.execute(
+        "UPDATE markets SET status = ?2, updated_slot = ?3 WHERE market_key = ?1",
+        params![market_key.to_string(), status, int(slot)],
+    )?;
+    Ok(())
+}
+
+fn set_market_remain_tokens(tx: &Transaction, market_key: u64, remain_tokens: u64, slot: u64) -> Result<()> {
+    tx.execute(
+        "UPDATE markets SET remain_tokens = ?2, updated_slot = ?3 WHERE market_key = ?1",
+        params![market_key.to_string(), int(remain_tokens), int(slot)],
+    )?;
+    Ok(())
+}
+
+fn apply_market_event(tx: &Transaction, ctx: &TxContext, event: &MarketEvent) -> Result<()> {
+    let slot = int(ctx.slot);
+
+    match event {
+        MarketEvent::MarketPublished(e) => {
+            tx.execute(
+                "INSERT INTO markets (
+                    market_key, address, creator, betting_token, title, status, create_fee,
+                    creator_fee_percentage, service_fee_percentage, charity_fee_percentage,
+                    referral_fee_percentage, published_slot, updated_slot
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, 'Approve', ?6, ?7, ?8, ?9, ?10, ?11, ?11)
+                 ON CONFLICT(market_key) DO NOTHING",
+                params![
+                    e.market_key.to_string(),
+                    e.market.to_string(),
+                    e.creator.to_string(),
+                    e.betting_token.to_string(),
+                    e.title,
+                    int(e.create_fee),
+                    int(e.creator_fee_percentage),
+                    int(e.service_fee_percentage),
+                    int(e.charity_fee_percentage),
+                    int(e.referral_fee_percentage),
+                    slot
+                ],
+            )?;
+            for answer_key in &e.answer_keys {
+                tx.execute(
+                    "INSERT OR IGNORE INTO answers (market_key, answer_key) VALUES (?1, ?2)",
+                    params![e.market_key.to_string(), answer_key.to_string()],
+                )?;
+            }
+        }
+        MarketEvent::BetPlaced(e) => {
+            tx.execute(
+                "INSERT INTO bets (market_key, voter, answer_key, referrer, bet_count, tokens, first_slot, last_slot)
+                 VALUES (?1, ?2, ?3, ?4, 1, ?5, ?6, ?6)
+                 ON CONFLICT(market_key, voter, answer_key) DO UPDATE SET
+                    bet_count = bet_count + 1,
+                    tokens = excluded.tokens,
+                    last_slot = excluded.last_slot",
+                params![
+                    e.market_key.to_string(),
+                    e.voter.to_string(),
+                    e.answer_key.to_string(),
+                    e.referrer.map(|referrer| referrer.to_string()),
+                    int(e.bet_total_tokens),
+                    slot
+                ],
+            )?;
+            tx.execute(
+                "UPDATE answers SET total_tokens = ?3 WHERE market_key = ?1 AND answer_key = ?2",
+                params![e.market_key.to_string(), e.answer_key.to_string(), int(e.answer_total_tokens)],
+            )?;
+            tx.execute(
+                "UPDATE markets SET total_tokens = ?2, remain_tokens = ?2, updated_slot = ?3
+                 WHERE market_key = ?1",
+                params![e.market_key.to_string(), int(e.market_total_tokens), slot],
+            )?;
+        }
+        MarketEvent::TokenReceived(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO claims (signature, kind, market_key, claimant, answer_key, amount, fee_rebate, slot)
+                 VALUES (?1, 'bet', ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    ctx.signature,
+                    e.market_key.to_string(),
+                    e.receiver.to_string(),
+                    e.answer_key.to_string(),
+                    int(e.received_tokens),
+                    int(e.fee_rebate_tokens),
+                    slot
+                ],
+            )?;
+            tx.execute(
+                "UPDATE bets SET claimed = 1 WHERE market_key = ?1 AND voter = ?2 AND answer_key = ?3",
+                params![e.market_key.to_string(), e.receiver.to_string(), e.answer_key.to_string()],
+            )?;
+            set_market_remain_tokens(tx, e.market_key, e.market_remain_tokens, ctx.slot)?;
+        }
+        MarketEvent::MarketFinished(e) => {
+            set_market_status(tx, e.market_key, "Finished", ctx.slot)?;
+            set_market_remain_tokens(tx, e.market_key, e.market_remain_tokens, ctx.slot)?;
+        }
+        MarketEvent::MarketSuccess(e) => {
+            tx.execute(
+                "UPDATE markets SET
+                    status = 'Success', correct_answer_key = ?2, creator_fee = ?3, service_fee = ?4,
+                    charity_fee = ?5, referral_fee = ?6, fee_discount = ?7, reward_base_tokens = ?8,
+                    remain_tokens = ?9, updated_slot = ?10
+                 WHERE market_key = ?1",
+                params![
+                    e.market_key.to_string(),
+                    e.answer_key.to_string(),
+                    int(e.creator_fee),
+                    int(e.service_fee),
+                    int(e.charity_fee),
+                    int(e.referral_fee),
+                    int(e.fee_discount),
+                    int(e.market_reward_base_tokens),
+                    int(e.market_remain_tokens),
+                    slot
+                ],
+            )?;
+        }
+        MarketEvent::MarketAdjourned(e) => set_market_status(tx, e.market_key, "Adjourn", ctx.slot)?,
+        MarketEvent::MarketForceAdjourned(e) => {
+            tx.execute(
+                "UPDATE markets SET status = 'Adjourn', force_adjourned = 1, updated_slot = ?2
+                 WHERE market_key = ?1",
+                params![e.market_key.to_string(), slot],
+            )?;
+        }
+        MarketEvent::MarketVoided(e) => {
+            tx.execute(
+                "UPDATE markets SET status = 'Voided', void_reason = ?2, remain_tokens = ?3, updated_slot = ?4
+                 WHERE market_key = ?1",
+                params![e.market_key.to_string(), i64::from(e.reason), int(e.refundable_tokens), slot],
+            )?;
+        }
+        MarketEvent::MarketFrozen(e) => {
+            tx.execute(
+                "UPDATE markets SET frozen = 1, updated_slot = ?2 WHERE market_key = ?1",
+                params![e.market_key.to_string(), slot],
+            )?;
+        }
+        MarketEvent::MarketUnfrozen(e) => {
+            tx.execute(
+                "UPDATE markets SET frozen = 0, updated_slot = ?2 WHERE market_key = ?1",
+                params![e.market_key.to_string(), slot],
+            )?;
+        }
+        MarketEvent::ReferralRewardClaimed(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO claims (signature, kind, market_key, claimant, amount, slot)
+                 VALUES (?1, 'referral', ?2, ?3, ?4, ?5)",
+                params![
+                    ctx.signature,
+                    e.market_key.to_string(),
+                    e.referrer.to_string(),
+                    int(e.reward_tokens),
+                    slot
+                ],
+            )?;
+        }
+        MarketEvent::TokensRetrieved(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO claims (signature, kind, market_key, claimant, amount, slot)
+                 VALUES (?1, 'retrieve', ?2, ?3, ?4, ?5)",
+                params![
+                    ctx.signature,
+                    e.market_key.to_string(),
+                    e.destination.to_string(),
+                    int(e.retrieved_tokens),
+                    slot
+                ],
+            )?;
+            set_market_remain_tokens(tx, e.market_key, 0, ctx.slot)?;
+        }
+        MarketEvent::SeriesMarketCreated(e) => {
+            // Emitted right after the MarketPublished event of the same market
+            tx.execute(
+                "UPDATE markets SET series_key = ?2, series_epoch = ?3, previous_market_key = ?4, updated_slot = ?5
+                 WHERE market_key = ?1",
+                params![
+                    e.market_key.to_string(),
+                    e.series_key.to_string(),
+                    int(e.epoch),
+                    (e.previous_market_key != 0).then(|| e.previous_market_key.to_string()),
+                    slot
+                ],
+            )?;
+        }
+        MarketEvent::SeedLiquidityAdded(e) => {
+            tx.execute(
+                "UPDATE markets SET seed_liquidity_tokens = ?2, updated_slot = ?3 WHERE market_key = ?1",
+                params![e.market_key.to_string(), int(e.seed_liquidity_tokens), slot],
+            )?;
+        }
+        MarketEvent::SeedLiquidityRefunded(e) => {
+            tx.execute(
+                "INSERT OR IGNORE INTO claims (signature, kind, market_key, claimant, amount, slot)
+                 VALUES (?1, 'seed_refund', ?2, ?3, ?4, ?5)",
+                params![
+                    ctx.signature,
+                    e.market_key.to_string(),
+                    e.creator.to_string(),
+                    int(e.amount),
+                    slot
+                ],
+            )?;
+        }
+        MarketEvent::ParlayPlaced(e) => {
+            // Legs are stored as "market_key:answer_key" pairs
+            let legs = e
+                .legs
+                .iter()
+                .map(|leg| format!("{}:{}", leg.market_key, leg.answer_key))
+                .collect::<Vec<_>>()
+                .join(",");
+            tx.execute(
+                "INSERT OR IGNORE INTO parlays (voter, parlay_key, pool, legs, stake, placed_slot)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    e.voter.to_string(),
+                    e.parlay_key.to_string(),
+                    e.pool.to_string(),
+                    legs,
+                    int(e.amount),
+                    slot
+                ],
+            )?;
+        }
+        MarketEvent::ParlaySettled(e) => {
+            tx.execute(
+                "UPDATE parlays SET settlement = ?3, payout = ?4, settled_slot = ?5
+                 WHERE voter = ?1 AND parlay_key = ?2",
+                params![
+                    e.voter.to_string(),
+                    e.parlay_key.to_string(),
+                    format!("{:?}", e.settlement),
+                    int(e.payout),
+                    slot
+                ],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn apply_governance_event(tx: &Transaction, ctx: &TxContext, event: &GovernanceEvent) -> Result<()> {
+    let slot = int(ctx.slot);
+
+    match event {
+        GovernanceEvent::GovernanceItemCreated(e) => {
+            tx.execute(
+                "INSERT INTO governance_items (
+                    quest_key, question, creator, phase, start_slot, quest_start_time, quest_end_time, updated_slot
+                 ) VALUES (?1, ?2, ?3, 'Quest', ?4, ?5, ?6, ?7)
+                 ON CONFLICT(quest_key) DO NOTHING",
+                params![
+                    e.quest_key.to_string(),
+                    e.question,
+                    e.creator.to_string(),
+                    int(e.start_slot),
+                    int(e.created_at),
+                    int(e.end_at),
+                    slot
+                ],
+            )?;
+        }
+        GovernanceEvent::QuestResultSet(e) => {
+            set_quest_result(tx, e.quest_key, &format!("{:?}", e.result), ctx.slot)?
+        }
+        GovernanceEvent::QuestResultMade(e) => {
+            set_quest_result(tx, e.quest_key, &format!("{:?}", e.result), ctx.slot)?
+        }
+        GovernanceEvent::QuestCancelled(e) => set_quest_result(tx, e.quest_key, "cancelled", ctx.slot)?,
+        GovernanceEvent::DecisionStarted(e) => {
+            tx.execute(
+                "UPDATE governance_items SET
+                    phase = 'Decision', decision_start_time = ?2, decision_end_time = ?3, updated_slot = ?4
+                 WHERE quest_key = ?1",
+                params![e.quest_key.to_string(), int(e.created_at), int(e.end_at), slot],
+            )?;
+        }
+        GovernanceEvent::DecisionResultSet(e) => {
+            set_decision_result(tx, e.quest_key, &format!("{:?}", e.result), ctx.slot)?
+        }
+        GovernanceEvent::DecisionResultMade(e) => {
+            set_decision_result(tx, e.quest_key, &format!("{:?}", e.result), ctx.slot)?
+        }
+        GovernanceEvent::DecisionCancelled(e) => set_decision_result(tx, e.quest_key, "cancelled", ctx.slot)?,
+        GovernanceEvent::AnswerStarted(e) => {
+            tx.execute(
+                "UPDATE governance_items SET
+                    phase = 'Answer', answer_start_time = ?2, answer_end_time = ?3, updated_slot = ?4
+                 WHERE quest_key = ?1",
+                params![e.quest_key.to_string(), int(e.created_at), int(e.end_at), slot],
+            )?;
+        }
+        GovernanceEvent::AnswerFinalized(e) => {
+            tx.execute(
+                "UPDATE governance_items SET phase = 'Finalized', answer_result = ?2, updated_slot = ?3
+                 WHERE quest_key = ?1",
+                params![e.quest_key.to_string(), e.winning_answer.to_string(), slot],
+            )?;
+        }
+        GovernanceEvent::AnswerCancelled(e) => {
+            tx.execute(
+                "UPDATE governance_items SET phase = 'Cancelled', answer_result = '0', updated_slot = ?2
+                 WHERE quest_key = ?1",
+                params![e.quest_key.to_string(), slot],
+            )?;
+        }
+        GovernanceEvent::VoteQuestCast(e) => {
+            let choice = format!("{:?}", e.vote_choice);
+            record_vote(tx, ctx, e.quest_key, "Quest", &e.voter.to_string(), &choice, e.votes)?;
+        }
+        GovernanceEvent::VoteDecisionCast(e) => {
+            let choice = format!("{:?}", e.vote_choice);
+            record_vote(tx, ctx, e.quest_key, "Decision", &e.voter.to_string(), &choice, e.votes)?;
+        }
+        GovernanceEvent::VoteAnswerCast(e) => {
+            let choice = e.answer_key.to_string();
+            record_vote(tx, ctx, e.quest_key, "Answer", &e.voter.to_string(), &choice, e.votes)?;
+        }
+        GovernanceEvent::AnswerVoteRevealed(e) => {
+            let choice = e.answer_key.to_string();
+            record_vote(tx, ctx, e.quest_key, "Answer", &e.voter.to_string(), &choice, e.votes)?;
+        }
+        GovernanceEvent::VoteProposalCast(e) => {
+            let choice = format!("{:?}", e.vote_choice);
+            record_vote(tx, ctx, e.proposal_key, "Proposal", &e.voter.to_string(), &choice, e.votes)?;
+        }
+        GovernanceEvent::QuestVoteChanged(e) => {
+            let choice = e.new_choice.as_ref().map(|choice| format!("{:?}", choice));
+            change_vote(tx, ctx, e.quest_key, "Quest", &e.voter.to_string(), choice.as_deref(), e.votes)?;
+        }
+        GovernanceEvent::DecisionVoteChanged(e) => {
+            let choice = e.new_choice.as_ref().map(|choice| format!("{:?}", choice));
+            change_vote(tx, ctx, e.quest_key, "Decision", &e.voter.to_string(), choice.as_deref(), e.votes)?;
+        }
+        GovernanceEvent::AnswerVoteChanged(e) => {
+            let choice = e.new_answer_key.map(|key| key.to_string());
+            change_vote(tx, ctx, e.quest_key, "Answer", &e.voter.to_string(), choice.as_deref(), e.votes)?;
+        }
+        GovernanceEvent::RewardDistributed(e) => {
+            tx.execute(
+                "INSERT INTO rewards (quest_key, voter, answer_key, vote_count, reward_amount, signature, slot)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT(quest_key, voter) DO UPDATE SET
+                    answer_key = excluded.answer_key,
+                    vote_count = excluded.vote_count,
+                    reward_amount = reward_amount + excluded.reward_amount,
+                    signature = excluded.signature,
+                    slot = excluded.slot
+                 WHERE signature != excluded.signature",
+                params![
+                    e.quest_key.to_string(),
+                    e.voter.to_string(),
+                    e.answer_key.to_string(),
+                    int(e.vote_count),
+                    int(e.reward_amount),
+                    ctx.signature,
+                    slot
+                ],
+            )?;
+        }
+        GovernanceEvent::GovernanceRewardsClaimed(e) => {
+            // A voter may claim a quest's phases in several calls; accumulate them in one row
+            let reward_amount = e.quest_reward + e.decision_reward + e.answer_reward;
+            tx.execute(
+                "INSERT INTO rewards (quest_key, voter, answer_key, vote_count, reward_amount, signature, slot)
+                 VALUES (?1, ?2, ?3, 0, ?4, ?5, ?6)
+                 ON CONFLICT(quest_key, voter) DO UPDATE SET
+                    answer_key = CASE excluded.answer_key WHEN '0' THEN answer_key ELSE excluded.answer_key END,
+                    reward_amount = reward_amount + excluded.reward_amount,
+                    signature = excluded.signature,
+                    slot = excluded.slot
+                 WHERE signature != excluded.signature",
+                params![
+                    e.quest_key.to_string(),
+                    e.voter.to_string(),
+                    e.answer_key.to_string(),
+                    int(reward_amount),
+                    ctx.signature,
+                    slot
+                ],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn set_quest_result(tx: &Transaction, quest_key: u64, result: &str, slot: u64) -> Result<()> {
+    tx.execute(
+        "UPDATE governance_items SET quest_result = ?2, updated_slot = ?3 WHERE quest_key = ?1",
+        params![quest_key.to_string(), result, int(slot)],
+    )?;
+    Ok(())
+}
+
+fn set_decision_result(tx: &Transaction, quest_key: u64, result: &str, slot: u64) -> Result<()> {
+    tx.execute(
+        "UPDATE governance_items SET decision_result = ?2, updated_slot = ?3 WHERE quest_key = ?1",
+        params![quest_key.to_string(), result, int(slot)],
+    )?;
+    Ok(())
+}
+
+fn record_vote(
+    tx: &Transaction,
+    ctx: &TxContext,
+    quest_key: u64,
+    phase: &str,
+    voter: &str,
+    choice: &str,
+    votes: u64,
+) -> Result<()> {
+    tx.execute(
+        "INSERT INTO votes (quest_key, phase, voter, choice, votes, signature, slot)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(quest_key, phase, voter) DO UPDATE SET
+            choice = excluded.choice,
+            votes = excluded.votes,
+            signature = excluded.signature,
+            slot = excluded.slot",
+        params![
+            quest_key.to_string(),
+            phase,
+            voter,
+            choice,
+            int(votes),
+            ctx.signature,
+            int(ctx.slot)
+        ],
+    )?;
+    Ok(())
+}
+
+/// Applies a changed vote; `choice` is None when the vote was revoked
+fn change_vote(
+    tx: &Transaction,
+    ctx: &TxContext,
+    quest_key: u64,
+    phase: &str,
+    voter: &str,
+    choice: Option<&str>,
+    votes: u64,
+) -> Result<()> {
+    match choice {
+        Some(choice) => record_vote(tx, ctx, quest_key, phase, voter, choice, votes),
+        None => {
+            tx.execute(
+                "DELETE FROM votes WHERE quest_key = ?1 AND phase = ?2 AND voter = ?3",
+                params![quest_key.to_string(), phase, voter],
+            )?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_round_trips_the_full_u64_range() {
+        for value in [0, i64::MAX as u64, i64::MAX as u64 + 1, u64::MAX] {
+            assert_eq!(int(value) as u64, value);
+        }
+    }
+}
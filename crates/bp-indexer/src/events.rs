@@ -0,0 +1,191 @@
+//! Decodes the Anchor events the indexer materializes.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use bp_client::boomplay_governance::instructions as governance;
+use bp_client::bp_market::events as market;
+
+pub enum MarketEvent {
+    MarketPublished(market::MarketPublished),
+    BetPlaced(market::BetPlaced),
+    TokenReceived(market::TokenReceived),
+    MarketFinished(market::MarketFinished),
+    MarketSuccess(market::MarketSuccess),
+    MarketAdjourned(market::MarketAdjourned),
+    MarketForceAdjourned(market::MarketForceAdjourned),
+    MarketVoided(market::MarketVoided),
+    MarketFrozen(market::MarketFrozen),
+    MarketUnfrozen(market::MarketUnfrozen),
+    ReferralRewardClaimed(market::ReferralRewardClaimed),
+    TokensRetrieved(market::TokensRetrieved),
+    ParlayPlaced(market::ParlayPlaced),
+    ParlaySettled(market::ParlaySettled),
+    SeriesMarketCreated(market::SeriesMarketCreated),
+    SeedLiquidityAdded(market::SeedLiquidityAdded),
+    SeedLiquidityRefunded(market::SeedLiquidityRefunded),
+}
+
+pub enum GovernanceEvent {
+    GovernanceItemCreated(governance::GovernanceItemCreated),
+    QuestResultSet(governance::QuestResultSet),
+    QuestResultMade(governance::QuestResultMade),
+    QuestCancelled(governance::QuestCancelled),
+    DecisionStarted(governance::DecisionStarted),
+    DecisionResultSet(governance::DecisionResultSet),
+    DecisionResultMade(governance::DecisionResultMade),
+    DecisionCancelled(governance::DecisionCancelled),
+    AnswerStarted(governance::AnswerStarted),
+    AnswerFinalized(governance::AnswerFinalized),
+    AnswerCancelled(governance::AnswerCancelled),
+    VoteQuestCast(governance::VoteQuestCast),
+    VoteDecisionCast(governance::VoteDecisionCast),
+    VoteAnswerCast(governance::VoteAnswerCast),
+    VoteProposalCast(governance::VoteProposalCast),
+    QuestVoteChanged(governance::QuestVoteChanged),
+    DecisionVoteChanged(governance::DecisionVoteChanged),
+    AnswerVoteChanged(governance::AnswerVoteChanged),
+    AnswerVoteRevealed(governance::AnswerVoteRevealed),
+    RewardDistributed(governance::RewardDistributed),
+    GovernanceRewardsClaimed(governance::GovernanceRewardsClaimed),
+}
+
+/// Tries each listed event type against the payload's discriminator.
+macro_rules! decode_event {
+    ($data:expr, $event_enum:ident { $($variant:ident => $event:ty),* $(,)? }) => {{
+        let data: &[u8] = $data;
+        $(
+            if data.starts_with(<$event as Discriminator>::DISCRIMINATOR) {
+                let mut payload = &data[<$event as Discriminator>::DISCRIMINATOR.len()..];
+                return Ok(Some($event_enum::$variant(<$event>::deserialize(&mut payload)?)));
+            }
+        )*
+        Ok(None)
+    }};
+}
+
+/// Decodes a bp-market event payload. Returns `None` for events the indexer does not track.
+pub fn decode_market_event(data: &[u8]) -> std::io::Result<Option<MarketEvent>> {
+    decode_event!(data, MarketEvent {
+        MarketPublished => market::MarketPublished,
+        BetPlaced => market::BetPlaced,
+        TokenReceived => market::TokenReceived,
+        MarketFinished => market::MarketFinished,
+        MarketSuccess => market::MarketSuccess,
+        MarketAdjourned => market::MarketAdjourned,
+        MarketForceAdjourned => market::MarketForceAdjourned,
+        MarketVoided => market::MarketVoided,
+        MarketFrozen => market::MarketFrozen,
+        MarketUnfrozen => market::MarketUnfrozen,
+        ReferralRewardClaimed => market::ReferralRewardClaimed,
+        TokensRetrieved => market::TokensRetrieved,
+        ParlayPlaced => market::ParlayPlaced,
+        ParlaySettled => market::ParlaySettled,
+        SeriesMarketCreated => market::SeriesMarketCreated,
+        SeedLiquidityAdded => market::SeedLiquidityAdded,
+        SeedLiquidityRefunded => market::SeedLiquidityRefunded,
+    })
+}
+
+/// Decodes a boomplay-governance event payload. Returns `None` for events the indexer does not track.
+pub fn decode_governance_event(data: &[u8]) -> std::io::Result<Option<GovernanceEvent>> {
+    decode_event!(data, GovernanceEvent {
+        GovernanceItemCreated => governance::GovernanceItemCreated,
+        QuestResultSet => governance::QuestResultSet,
+        QuestResultMade => governance::QuestResultMade,
+        QuestCancelled => governance::QuestCancelled,
+        DecisionStarted => governance::DecisionStarted,
+        DecisionResultSet => governance::DecisionResultSet,
+        DecisionResultMade => governance::DecisionResultMade,
+        DecisionCancelled => governance::DecisionCancelled,
+        AnswerStarted => governance::AnswerStarted,
+        AnswerFinalized => governance::AnswerFinalized,
+        AnswerCancelled => governance::AnswerCancelled,
+        VoteQuestCast => governance::VoteQuestCast,
+        VoteDecisionCast => governance::VoteDecisionCast,
+        VoteAnswerCast => governance::VoteAnswerCast,
+        VoteProposalCast => governance::VoteProposalCast,
+        QuestVoteChanged => governance::QuestVoteChanged,
+        DecisionVoteChanged => governance::DecisionVoteChanged,
+        AnswerVoteChanged => governance::AnswerVoteChanged,
+        AnswerVoteRevealed => governance::AnswerVoteRevealed,
+        RewardDistributed => governance::RewardDistributed,
+        GovernanceRewardsClaimed => governance::GovernanceRewardsClaimed,
+    })
+}
+
+impl MarketEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MarketEvent::MarketPublished(_) => "MarketPublished",
+            MarketEvent::BetPlaced(_) => "BetPlaced",
+            MarketEvent::TokenReceived(_) => "TokenReceived",
+            MarketEvent::MarketFinished(_) => "MarketFinished",
+            MarketEvent::MarketSuccess(_) => "MarketSuccess",
+            MarketEvent::MarketAdjourned(_) => "MarketAdjourned",
+            MarketEvent::MarketForceAdjourned(_) => "MarketForceAdjourned",
+            MarketEvent::MarketVoided(_) => "MarketVoided",
+            MarketEvent::MarketFrozen(_) => "MarketFrozen",
+            MarketEvent::MarketUnfrozen(_) => "MarketUnfrozen",
+            MarketEvent::ReferralRewardClaimed(_) => "ReferralRewardClaimed",
+            MarketEvent::TokensRetrieved(_) => "TokensRetrieved",
+            MarketEvent::ParlayPlaced(_) => "ParlayPlaced",
+            MarketEvent::ParlaySettled(_) => "ParlaySettled",
+            MarketEvent::SeriesMarketCreated(_) => "SeriesMarketCreated",
+            MarketEvent::SeedLiquidityAdded(_) => "SeedLiquidityAdded",
+            MarketEvent::SeedLiquidityRefunded(_) => "SeedLiquidityRefunded",
+        }
+    }
+}
+
+impl GovernanceEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GovernanceEvent::GovernanceItemCreated(_) => "GovernanceItemCreated",
+            GovernanceEvent::QuestResultSet(_) => "QuestResultSet",
+            GovernanceEvent::QuestResultMade(_) => "QuestResultMade",
+            GovernanceEvent::QuestCancelled(_) => "QuestCancelled",
+            GovernanceEvent::DecisionStarted(_) => "DecisionStarted",
+            GovernanceEvent::DecisionResultSet(_) => "DecisionResultSet",
+            GovernanceEvent::DecisionResultMade(_) => "DecisionResultMade",
+            GovernanceEvent::DecisionCancelled(_) => "DecisionCancelled",
+            GovernanceEvent::AnswerStarted(_) => "AnswerStarted",
+            GovernanceEvent::AnswerFinalized(_) => "AnswerFinalized",
+            GovernanceEvent::AnswerCancelled(_) => "AnswerCancelled",
+            GovernanceEvent::VoteQuestCast(_) => "VoteQuestCast",
+            GovernanceEvent::VoteDecisionCast(_) => "VoteDecisionCast",
+            GovernanceEvent::VoteAnswerCast(_) => "VoteAnswerCast",
+            GovernanceEvent::VoteProposalCast(_) => "VoteProposalCast",
+            GovernanceEvent::QuestVoteChanged(_) => "QuestVoteChanged",
+            GovernanceEvent::DecisionVoteChanged(_) => "DecisionVoteChanged",
+            GovernanceEvent::AnswerVoteChanged(_) => "AnswerVoteChanged",
+            GovernanceEvent::AnswerVoteRevealed(_) => "AnswerVoteRevealed",
+            GovernanceEvent::RewardDistributed(_) => "RewardDistributed",
+            GovernanceEvent::GovernanceRewardsClaimed(_) => "GovernanceRewardsClaimed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Event;
+    use bp_client::bp_market::constant::EVENT_SCHEMA_VERSION;
+
+    #[test]
+    fn test_decode_market_event_by_discriminator() {
+        let data = market::MarketFinished {
+            version: EVENT_SCHEMA_VERSION,
+            market_key: 7,
+            market_total_tokens: 100,
+            market_remain_tokens: 100,
+            finished_by: Default::default(),
+            timestamp: 0,
+        }
+        .data();
+
+        match decode_market_event(&data).unwrap() {
+            Some(MarketEvent::MarketFinished(event)) => assert_eq!(event.market_key, 7),
+            _ => panic!("expected MarketFinished"),
+        }
+        assert!(decode_governance_event(&data).unwrap().is_none());
+    }
+}
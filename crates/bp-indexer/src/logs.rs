@@ -0,0 +1,72 @@
+//! Extracts Anchor event payloads from transaction log messages.
+
+use anchor_lang::prelude::Pubkey;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Returns the base64-decoded `Program data:` payloads emitted while `program_id` was the
+/// executing program, in log order. Payloads emitted by other programs in the same
+/// transaction (including CPIs) are skipped.
+pub fn program_data(logs: &[String], program_id: &Pubkey) -> Vec<Vec<u8>> {
+    let program_id = program_id.to_string();
+    let mut invoke_stack: Vec<&str> = Vec::new();
+    let mut payloads = Vec::new();
+
+    for log in logs {
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if invoke_stack.last() == Some(&program_id.as_str()) {
+                if let Ok(payload) = STANDARD.decode(data.trim()) {
+                    payloads.push(payload);
+                }
+            }
+            continue;
+        }
+
+        let mut parts = rest.split_whitespace();
+        let (Some(id), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        match action {
+            "invoke" => invoke_stack.push(id),
+            "success" | "failed:" => {
+                invoke_stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    payloads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_program_data_only_from_target_program() {
+        let program = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let encoded = STANDARD.encode([1u8, 2, 3]);
+
+        let lines = logs(&[
+            &format!("Program {} invoke [1]", program),
+            "Program log: Instruction: Bet",
+            &format!("Program {} invoke [2]", other),
+            "Program data: AAAA",
+            &format!("Program {} success", other),
+            &format!("Program data: {}", encoded),
+            &format!("Program {} consumed 1000 of 200000 compute units", program),
+            &format!("Program {} success", program),
+        ]);
+
+        assert_eq!(program_data(&lines, &program), vec![vec![1u8, 2, 3]]);
+        assert_eq!(program_data(&lines, &other), vec![vec![0u8, 0, 0]]);
+    }
+}
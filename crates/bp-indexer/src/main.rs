@@ -0,0 +1,205 @@
+//! Indexes bp-market and boomplay-governance events into SQLite.
+//!
+//! The indexer walks each program's transaction history oldest-first, decodes the Anchor
+//! events in the transaction logs and materializes markets, answers, bets, claims, governance
+//! items, votes and rewards. Each program keeps its own cursor, so a restarted indexer picks
+//! up after the last transaction it stored.
+//!
+//! ```text
+//! bp-indexer --rpc-url http://127.0.0.1:8899 --database bp-indexer.sqlite
+//! ```
+
+mod db;
+mod events;
+mod logs;
+
+use std::{path::PathBuf, str::FromStr, thread, time::Duration};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{Context, Result};
+use clap::Parser;
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use solana_transaction_status_client_types::{option_serializer::OptionSerializer, UiTransactionEncoding};
+
+use crate::db::{Database, TxContext};
+use crate::events::{decode_governance_event, decode_market_event};
+
+/// Maximum page size accepted by `getSignaturesForAddress`
+const SIGNATURE_PAGE_LIMIT: usize = 1_000;
+
+#[derive(Parser)]
+#[command(about = "Indexes bp-market and boomplay-governance events into SQLite")]
+struct Args {
+    /// RPC endpoint, e.g. a local solana-test-validator
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    rpc_url: String,
+    /// SQLite database file, created if missing
+    #[arg(long, default_value = "bp-indexer.sqlite")]
+    database: PathBuf,
+    /// Seconds to wait between polls
+    #[arg(long, default_value_t = 5)]
+    poll_interval: u64,
+    /// Index everything available once and exit
+    #[arg(long)]
+    once: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Program {
+    Market,
+    Governance,
+}
+
+impl Program {
+    fn id(self) -> Pubkey {
+        match self {
+            Program::Market => bp_client::MARKET_PROGRAM_ID,
+            Program::Governance => bp_client::GOVERNANCE_PROGRAM_ID,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Program::Market => "bp-market",
+            Program::Governance => "boomplay-governance",
+        }
+    }
+}
+
+struct SignatureInfo {
+    signature: String,
+    slot: u64,
+    block_time: Option<i64>,
+    failed: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let rpc = RpcClient::new_with_commitment(args.rpc_url.clone(), CommitmentConfig::confirmed());
+    let mut database = Database::open(&args.database)
+        .with_context(|| format!("opening {}", args.database.display()))?;
+
+    loop {
+        for program in [Program::Market, Program::Governance] {
+            match sync_program(&rpc, &mut database, program) {
+                Ok(0) => {}
+                Ok(indexed) => println!("{}: indexed {} transactions", program.name(), indexed),
+                // Keep polling through transient RPC failures
+                Err(err) if !args.once => eprintln!("{}: {:#}", program.name(), err),
+                Err(err) => return Err(err.context(format!("syncing {}", program.name()))),
+            }
+        }
+
+        if args.once {
+            return Ok(());
+        }
+        thread::sleep(Duration::from_secs(args.poll_interval));
+    }
+}
+
+/// Indexes every transaction newer than the program's cursor, oldest first.
+fn sync_program(rpc: &RpcClient, database: &mut Database, program: Program) -> Result<usize> {
+    let until = database
+        .cursor(program.name())?
+        .map(|signature| Signature::from_str(&signature))
+        .transpose()?;
+    let signatures = new_signatures(rpc, &program.id(), until)?;
+
+    for info in &signatures {
+        // Failed transactions emit no events but still advance the cursor
+        let logs = if info.failed {
+            Vec::new()
+        } else {
+            transaction_logs(rpc, &info.signature)?
+        };
+        let payloads = logs::program_data(&logs, &program.id());
+        let ctx = TxContext {
+            signature: &info.signature,
+            slot: info.slot,
+            block_time: info.block_time,
+        };
+
+        match program {
+            Program::Market => {
+                let events = payloads
+                    .iter()
+                    .filter_map(|payload| decode_market_event(payload).transpose())
+                    .collect::<std::io::Result<Vec<_>>>()
+                    .with_context(|| format!("decoding events of {}", info.signature))?;
+                database.record_market_transaction(program.name(), &ctx, &events)?;
+            }
+            Program::Governance => {
+                let events = payloads
+                    .iter()
+                    .filter_map(|payload| decode_governance_event(payload).transpose())
+                    .collect::<std::io::Result<Vec<_>>>()
+                    .with_context(|| format!("decoding events of {}", info.signature))?;
+                database.record_governance_transaction(program.name(), &ctx, &events)?;
+            }
+        }
+    }
+
+    Ok(signatures.len())
+}
+
+/// Pages backwards through the program's signatures until `until`, then returns them oldest first.
+fn new_signatures(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    until: Option<Signature>,
+) -> Result<Vec<SignatureInfo>> {
+    let mut signatures = Vec::new();
+    let mut before = None;
+
+    loop {
+        let page = rpc.get_signatures_for_address_with_config(
+            program_id,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(SIGNATURE_PAGE_LIMIT),
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )?;
+
+        let Some(last) = page.last() else {
+            break;
+        };
+        before = Some(Signature::from_str(&last.signature)?);
+        let is_last_page = page.len() < SIGNATURE_PAGE_LIMIT;
+
+        signatures.extend(page.into_iter().map(|status| SignatureInfo {
+            signature: status.signature,
+            slot: status.slot,
+            block_time: status.block_time,
+            failed: status.err.is_some(),
+        }));
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    signatures.reverse();
+    Ok(signatures)
+}
+
+fn transaction_logs(rpc: &RpcClient, signature: &str) -> Result<Vec<String>> {
+    let transaction = rpc.get_transaction_with_config(
+        &Signature::from_str(signature)?,
+        RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Json),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        },
+    )?;
+
+    Ok(match transaction.transaction.meta.map(|meta| meta.log_messages) {
+        Some(OptionSerializer::Some(logs)) => logs,
+        _ => Vec::new(),
+    })
+}
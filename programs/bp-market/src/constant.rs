@@ -2,4 +2,10 @@ pub const BASIS_POINTS: u16 = 10_000;
 pub const MAX_PERCENTAGE_BASIS_POINTS: u128 = 100_000_000;
 pub const SECONDS_IN_A_YEAR: u64 = 31_536_000; // 365 days * 24 hours * 60 minutes * 60 seconds
 pub const SUCCESS_MARKET_VALIDITY_DATE : u64 =  15_552_000; // 180 days * 24 hours * 60 minutes * 60 seconds
-pub const ADJOURN_MARKET_VALIDITY_DATE : u64 =  15_552_000; // 180 days * 24 hours * 60 minutes * 60 seconds
\ No newline at end of file
+pub const ADJOURN_MARKET_VALIDITY_DATE : u64 =  15_552_000; // 180 days * 24 hours * 60 minutes * 60 seconds
+pub const CONFIG_ACCOUNT_VERSION: u8 = 1;
+pub const MARKET_ACCOUNT_VERSION: u8 = 1;
+pub const DEFAULT_RESOLUTION_DEADLINE : u64 =  2_592_000; // 30 days * 24 hours * 60 minutes * 60 seconds
+pub const DEFAULT_MIN_PARLAY_OUTCOME_STAKE: u64 = 1_000_000_000; // 1 token at 9 decimals
+pub const APPROVE_MARKET_VALIDITY_DATE : u64 =  15_552_000; // 180 days an approved market may stay open before it counts as abandoned
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
@@ -54,4 +54,78 @@ pub enum ProgramErrorCode {
     InsufficientFunds,
     #[msg("Bet amount must be greater than zero")]
     InvalidBetAmount,
+    #[msg("Market/Bet: Invalid referrer")]
+    InvalidReferrer,
+    #[msg("Market/Bet: Referral accounts are required when a referrer is given")]
+    ReferralAccountsMissing,
+    #[msg("Market/ClaimReferral: Referral reward already claimed")]
+    ReferralAlreadyClaimed,
+    #[msg("Market/ClaimReferral: No referral reward to claim")]
+    NoReferralReward,
+    #[msg("Referral fee cannot exceed 100% of the service fee")]
+    InvalidReferralFee,
+    #[msg("Fee discount tiers must be sorted by NFT count and capped at 100%")]
+    InvalidFeeDiscountTiers,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Market is frozen")]
+    MarketFrozen,
+    #[msg("Market is already in the requested freeze state")]
+    MarketFreezeUnchanged,
+    #[msg("Market/ForceAdjourn: Resolution deadline has not passed")]
+    ResolutionDeadlineNotReached,
+    #[msg("Resolution deadline must be greater than zero")]
+    InvalidResolutionDeadline,
+    #[msg("Minimum parlay outcome stake must be greater than zero")]
+    InvalidMinParlayOutcomeStake,
+    #[msg("Vault balance does not cover the market's outstanding payouts")]
+    VaultBalanceMismatch,
+    #[msg("Account is already on the latest layout version")]
+    AccountAlreadyMigrated,
+    #[msg("Account data does not match the expected layout")]
+    InvalidAccountData,
+    #[msg("Market/Parlay: A parlay needs between 2 and 4 legs")]
+    InvalidParlayLegCount,
+    #[msg("Market/Parlay: Legs must be on distinct markets in ascending market key order")]
+    InvalidParlayLegOrder,
+    #[msg("Market/Parlay: Leg accounts do not match the parlay's markets")]
+    InvalidParlayLegAccount,
+    #[msg("Market/Parlay: Pool id does not match the legs' markets")]
+    InvalidParlayPool,
+    #[msg("Market/Parlay: Maximum number of answer combinations in this pool has been reached")]
+    MaxParlayOutcomesReached,
+    #[msg("Market/Parlay: Stake is below the minimum for a new answer combination")]
+    ParlayOutcomeStakeTooLow,
+    #[msg("Market/Parlay: Every leg's market must be resolved before settling")]
+    ParlayLegNotResolved,
+    #[msg("Market/Parlay: Pool has already been settled")]
+    ParlayPoolAlreadySettled,
+    #[msg("Market title is too long")]
+    TitleTooLong,
+    #[msg("Market/Series: Duration and interval must be greater than zero")]
+    InvalidSeriesSchedule,
+    #[msg("Market/Series: Series key is out of range")]
+    InvalidSeriesKey,
+    #[msg("Market/Series: Series is not active")]
+    SeriesInactive,
+    #[msg("Market/Series: Epoch is not the series' current epoch")]
+    InvalidSeriesEpoch,
+    #[msg("Market/Series: Every epoch of the series has been created")]
+    SeriesCompleted,
+    #[msg("Market/Series: Series is already in the requested state")]
+    SeriesStateUnchanged,
+    #[msg("Market/Series: Market does not belong to this series")]
+    NotSeriesMarket,
+    #[msg("Market/Series: Market duration has not elapsed")]
+    SeriesMarketStillOpen,
+    #[msg("Market/SeedLiquidity: Market has no seed liquidity to refund")]
+    NoSeedLiquidity,
+    #[msg("Market/SeedLiquidity: Seed liquidity is only refunded on adjourned or voided markets")]
+    SeedLiquidityNotRefundable,
+    #[msg("Market/Bet: Referral accounts must be omitted when no referrer is given")]
+    ReferralAccountsUnexpected,
+    #[msg("Market/Bet: The governance program is required to lock the stake behind a fee discount")]
+    GovernanceProgramMissing,
+    #[msg("Program is already in the requested pause state")]
+    PauseStateUnchanged,
 }
@@ -1,66 +1,401 @@
+//! Every event emitted by the market program.
+//!
+//! Each event carries the `version` of its schema (`EVENT_SCHEMA_VERSION`), the
+//! amounts moved by the instruction, the running totals left on the affected
+//! accounts and the block timestamp, so an indexer can rebuild market state from
+//! the event stream alone.
+
 use anchor_lang::prelude::*;
 
+use crate::{
+    instructions::AccountType,
+    states::{FeeDiscountTier, ParlayLeg, ParlayLegResult, ParlaySettlement},
+};
+
+// Config
+
 #[event]
-pub struct ExchangePaused {
-    pub paused_by: Pubkey,
+pub struct ConfigInitialized {
+    pub version: u8,
+    pub owner: Pubkey,
+    pub base_token: Pubkey,
+    pub cojam_fee_account: Pubkey,
+    pub charity_fee_account: Pubkey,
+    pub remain_account: Pubkey,
+    pub resolution_deadline: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ExchangeUnpaused {
-    pub unpaused_by: Pubkey,
+pub struct OwnerUpdated {
+    pub version: u8,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountUpdated {
+    pub version: u8,
+    pub account_type: AccountType,
+    pub old_account: Pubkey,
+    pub new_account: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseTokenUpdated {
+    pub version: u8,
+    pub old_token: Pubkey,
+    pub new_token: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeeUpdated {
+    pub version: u8,
+    pub old_referral_fee_percentage: u64,
+    pub new_referral_fee_percentage: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeDiscountTiersUpdated {
+    pub version: u8,
+    pub tiers: Vec<FeeDiscountTier>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResolutionDeadlineUpdated {
+    pub version: u8,
+    pub old_resolution_deadline: u64,
+    pub new_resolution_deadline: u64,
+    pub updated_by: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct TokenPaused {
+pub struct MinParlayOutcomeStakeUpdated {
+    pub version: u8,
+    pub old_min_parlay_outcome_stake: u64,
+    pub new_min_parlay_outcome_stake: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserLocked {
+    pub version: u8,
+    pub user: Pubkey,
+    pub locked_by: Pubkey,
+    pub locked_user_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserUnlocked {
+    pub version: u8,
+    pub user: Pubkey,
+    pub unlocked_by: Pubkey,
+    pub locked_user_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketsPaused {
+    pub version: u8,
     pub paused_by: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct TokenUnpaused {
+pub struct MarketsUnpaused {
+    pub version: u8,
     pub unpaused_by: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct OwnershipTransferred {
-    pub previous_owner: Pubkey,
-    pub new_owner: Pubkey,
+pub struct ConfigMigrated {
+    pub version: u8,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// Market lifecycle
+
+#[event]
+pub struct MarketPublished {
+    pub version: u8,
+    pub market_key: u64,
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub betting_token: Pubkey,
+    pub title: String,
+    pub create_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub charity_fee_percentage: u64,
+    pub referral_fee_percentage: u64,
+    pub answer_keys: Vec<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketFinished {
+    pub version: u8,
+    pub market_key: u64,
+    pub market_total_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub finished_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketSuccess {
+    pub version: u8,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub creator_fee: u64,
+    pub service_fee: u64,
+    pub charity_fee: u64,
+    pub referral_fee: u64,
+    pub fee_discount: u64,
+    pub market_total_tokens: u64,
+    pub market_reward_base_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketAdjourned {
+    pub version: u8,
+    pub market_key: u64,
+    pub refundable_tokens: u64,
+    pub adjourned_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketForceAdjourned {
+    pub version: u8,
+    pub market_key: u64,
+    pub refundable_tokens: u64,
+    pub adjourned_by: Pubkey,
+    pub finish_time: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketVoided {
+    pub version: u8,
+    pub market_key: u64,
+    pub reason: u8,
+    pub voided_by: Pubkey,
+    pub refundable_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketFrozen {
+    pub version: u8,
+    pub market_key: u64,
+    pub frozen_by: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct FeeUpdated {
-    pub old_fee_bps: u16,
-    pub new_fee_bps: u16,
+pub struct MarketUnfrozen {
+    pub version: u8,
+    pub market_key: u64,
+    pub unfrozen_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketMigrated {
+    pub version: u8,
+    pub market_key: u64,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+// Templates and series
+
+#[event]
+pub struct MarketTemplateCreated {
+    pub version: u8,
+    pub template_key: u64,
+    pub template: Pubkey,
+    pub creator: Pubkey,
+    pub title_pattern: String,
+    pub betting_token: Pubkey,
+    pub create_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub charity_fee_percentage: u64,
+    pub answer_keys: Vec<u64>,
+    pub duration: u64,
+    pub created_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketSeriesCreated {
+    pub version: u8,
+    pub series_key: u64,
+    pub series: Pubkey,
+    pub template_key: u64,
+    pub start_time: u64,
+    pub interval: u64,
+    pub max_epochs: u64,
+    pub created_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketSeriesActiveSet {
+    pub version: u8,
+    pub series_key: u64,
+    pub active: bool,
     pub updated_by: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct FeesWithdrawn {
-    pub recipient: Pubkey,
-    pub usdt_amount: u64,
-    pub usdp_amount: u64,
+pub struct SeriesMarketCreated {
+    pub version: u8,
+    pub series_key: u64,
+    pub series: Pubkey,
+    pub epoch: u64,
+    pub market_key: u64,
+    pub previous_market_key: u64,
+    pub cranked_by: Pubkey,
     pub timestamp: i64,
 }
 
+// Betting and payouts
+
 #[event]
-pub struct UsdpBought {
-    pub buyer: Pubkey,
-    pub usdt_amount: u64,
-    pub usdp_amount: u64,
-    pub fee_amount: u64,
+pub struct SeedLiquidityAdded {
+    pub version: u8,
+    pub market_key: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub seed_liquidity_tokens: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct UsdpSold {
-    pub seller: Pubkey,
-    pub usdp_amount: u64,
-    pub usdt_amount: u64,
-    pub fee_amount: u64,
+pub struct SeedLiquidityRefunded {
+    pub version: u8,
+    pub market_key: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct BetPlaced {
+    pub version: u8,
+    pub voter: Pubkey,
+    pub market_key: u64,
+    pub market: Pubkey,
+    pub answer_key: u64,
+    pub amount: u64,
+    pub referrer: Option<Pubkey>,
+    pub fee_discount_percentage: u64,
+    /// Voter's stake on this answer after the bet
+    pub bet_total_tokens: u64,
+    pub answer_total_tokens: u64,
+    pub market_total_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenReceived {
+    pub version: u8,
+    pub receiver: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+    pub bet_tokens: u64,
+    pub received_tokens: u64,
+    pub fee_rebate_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub fee_discount_remain_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardClaimed {
+    pub version: u8,
+    pub referrer: Pubkey,
+    pub market_key: u64,
+    pub referred_tokens: u64,
+    pub reward_tokens: u64,
+    pub referral_remain_tokens: u64,
+    /// Referrer's lifetime claimed rewards for the market's betting token
+    pub claimed_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensRetrieved {
+    pub version: u8,
+    pub market_key: u64,
+    pub destination: Pubkey,
+    pub retrieved_by: Pubkey,
+    pub unclaimed_winning_tokens: u64,
+    pub unclaimed_referral_tokens: u64,
+    pub unclaimed_fee_discount_tokens: u64,
+    pub retrieved_tokens: u64,
+    pub timestamp: i64,
+}
+
+// Parlays
+
+#[event]
+pub struct ParlayPlaced {
+    pub version: u8,
+    pub voter: Pubkey,
+    pub parlay_key: u64,
+    pub pool: Pubkey,
+    pub legs: Vec<ParlayLeg>,
+    pub amount: u64,
+    /// Stake on this answer combination after the parlay
+    pub outcome_stake: u64,
+    pub pool_total_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParlayPoolSettled {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub market_keys: Vec<u64>,
+    pub leg_results: Vec<ParlayLegResult>,
+    pub total_stake: u64,
+    pub winning_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParlaySettled {
+    pub version: u8,
+    pub voter: Pubkey,
+    pub parlay_key: u64,
+    pub pool: Pubkey,
+    pub stake: u64,
+    pub settlement: ParlaySettlement,
+    pub payout: u64,
+    pub pool_remain_tokens: u64,
+    pub timestamp: i64,
+}
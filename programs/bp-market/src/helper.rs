@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token;
 
 use crate::{
@@ -6,7 +7,10 @@ use crate::{
         ADJOURN_MARKET_VALIDITY_DATE, SUCCESS_MARKET_VALIDITY_DATE,
     },
     error::ProgramErrorCode,
-    states::{MarketAccount, MarketStatus},
+    states::{
+        Answer, AnswerAccount, MarketAccount, MarketStatus, ANSWER_SEED, MAX_ANWSER,
+        MAX_TITLE_LEN,
+    },
 };
 
 pub fn transfer_token_or_point_to_pool<'info>(
@@ -57,15 +61,147 @@ pub fn transfer_token_from_pool_to_user<'info>(
     )
 }
 
+/// Parameters of a new market, either passed to publish_market or read from a template.
+/// create_market_template takes them with `title` holding the title pattern.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MarketParams {
+    pub creator: Pubkey,
+    pub title: String,
+    pub betting_token: Pubkey,
+    pub create_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub charity_fee_percentage: u64,
+    pub answer_keys: Vec<u64>,
+}
+
+pub fn validate_answer_keys(answer_keys: &[u64]) -> Result<()> {
+    if answer_keys.is_empty() {
+        return Err(ProgramErrorCode::NoAnswersProvided.into());
+    }
+
+    if answer_keys.len() > MAX_ANWSER {
+        return Err(ProgramErrorCode::MaxAnswersReached.into());
+    }
+
+    // Check for duplicate answer keys
+    let mut unique_keys = answer_keys.to_vec();
+    unique_keys.sort();
+    unique_keys.dedup();
+    if unique_keys.len() != answer_keys.len() {
+        return Err(ProgramErrorCode::AnswerAlreadyExists.into());
+    }
+
+    Ok(())
+}
+
+/// Fills a freshly initialized market and its answers and approves it right away.
+/// Bumps and versioning are left to the caller.
+pub fn open_market(
+    market_account: &mut MarketAccount,
+    answer_account: &mut AnswerAccount,
+    market_key: u64,
+    params: &MarketParams,
+    referral_fee_percentage: u64,
+    approve_time: i64,
+) -> Result<()> {
+    validate_answer_keys(&params.answer_keys)?;
+    require!(params.title.len() <= MAX_TITLE_LEN, ProgramErrorCode::TitleTooLong);
+
+    // Draft market
+    market_account.creator = params.creator;
+    market_account.market_key = market_key;
+    market_account.betting_token = params.betting_token;  // Save betting token for this market
+    market_account.title = params.title.clone();
+    market_account.creator_fee = params.create_fee;
+    market_account.creator_fee_percentage = params.creator_fee_percentage;
+    market_account.service_fee_percentage = params.service_fee_percentage;
+    market_account.charity_fee_percentage = params.charity_fee_percentage;
+    market_account.referral_fee_percentage = referral_fee_percentage;
+
+    //approve market
+    market_account.status = MarketStatus::Approve;
+    market_account.approve_time = approve_time as u64;
+
+    // Add answer keys
+    answer_account.answers = Vec::with_capacity(MAX_ANWSER);
+    answer_account.exist = true;
+
+    for answer_key in &params.answer_keys {
+        answer_account.answers.push(Answer {
+            answer_key: *answer_key,
+            answer_total_tokens: 0,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the vault still holds every token the market owes to winners, referrers,
+/// fee-rebate holders and the seed liquidity creator. Called after each payout.
+///
+/// Anyone can send tokens to the vault, so a balance above what is owed is not an error;
+/// retrieve_tokens sweeps that surplus to the remain account with the unclaimed tokens.
+pub fn assert_vault_covers_market(market_account: &MarketAccount, vault_amount: u64) -> Result<()> {
+    // On success the seed liquidity is already part of market_remain_tokens
+    let unrefunded_seed_tokens = if market_account.status == MarketStatus::Success {
+        0
+    } else {
+        market_account.seed_liquidity_tokens
+    };
+    let outstanding = market_account
+        .market_remain_tokens
+        .checked_add(market_account.referral_remain_tokens)
+        .and_then(|result| result.checked_add(market_account.fee_discount_remain_tokens))
+        .and_then(|result| result.checked_add(unrefunded_seed_tokens))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    require!(
+        vault_amount >= outstanding,
+        ProgramErrorCode::VaultBalanceMismatch
+    );
+
+    Ok(())
+}
+
+/// Deserializes a market passed in `remaining_accounts` and checks it is the market for `market_key`.
+pub fn load_leg_market(info: &AccountInfo, market_key: u64) -> Result<MarketAccount> {
+    require_keys_eq!(*info.owner, crate::ID, ProgramErrorCode::InvalidParlayLegAccount);
+    let market_account = MarketAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require!(
+        market_account.market_key == market_key,
+        ProgramErrorCode::InvalidParlayLegAccount
+    );
+
+    Ok(market_account)
+}
+
+/// Deserializes the answer account of `market_key` passed in `remaining_accounts`.
+pub fn load_leg_answers(info: &AccountInfo, market_key: u64) -> Result<AnswerAccount> {
+    require_keys_eq!(*info.owner, crate::ID, ProgramErrorCode::InvalidParlayLegAccount);
+    let answer_account = AnswerAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let expected = Pubkey::create_program_address(
+        &[ANSWER_SEED.as_bytes(), &market_key.to_le_bytes(), &[answer_account.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| ProgramErrorCode::InvalidParlayLegAccount)?;
+    require_keys_eq!(info.key(), expected, ProgramErrorCode::InvalidParlayLegAccount);
+
+    Ok(answer_account)
+}
+
 pub fn is_retrieve_available(market_account: &MarketAccount, clock: &Clock) -> Result<bool> {
     require!(
         market_account.status == MarketStatus::Success
-            || market_account.status == MarketStatus::Adjourn,
+            || market_account.status == MarketStatus::Adjourn
+            || market_account.status == MarketStatus::Voided,
         ProgramErrorCode::CannotRetrieveToken
     );
 
     let diff = if market_account.status == MarketStatus::Success {
         clock.unix_timestamp as u64 - market_account.success_time
+    } else if market_account.status == MarketStatus::Voided {
+        clock.unix_timestamp as u64 - market_account.void_time
     } else {
         clock.unix_timestamp as u64 - market_account.adjourn_time
     };
@@ -78,3 +214,36 @@ pub fn is_retrieve_available(market_account: &MarketAccount, clock: &Clock) -> R
 
     Ok(is_available)
 }
+
+
+/// Grows an account to `new_len`, topping up its lamports from `payer` so it stays rent exempt.
+/// New bytes are zero-initialized.
+pub fn realloc_account<'info>(
+    account: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_len);
+    let lamports = account.lamports();
+    if rent_exempt_lamports > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program,
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_lamports - lamports,
+        )?;
+    }
+
+    account.resize(new_len)?;
+
+    Ok(())
+}
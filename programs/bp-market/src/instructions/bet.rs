@@ -4,27 +4,35 @@ use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
 use anchor_spl::token_interface::{Mint, TokenAccount};
+use boomplay_governance::constant::VOTER_STAKE_SEED;
+use boomplay_governance::cpi::accounts::LockVoterStake;
+use boomplay_governance::program::BoomplayGovernance;
+use boomplay_governance::states::VoterStake;
 
-use crate::states::{ANSWER_SEED, CONFIG_SEED, AnswerAccount, BettingAccount, ConfigAccount, MarketAccount, MarketStatus, BETTING_SEED};
+use crate::states::{ANSWER_SEED, CONFIG_SEED, AnswerAccount, BettingAccount, ConfigAccount, MarketAccount, MarketStatus, BETTING_SEED, REFERRAL_MARKET_SEED, REFERRAL_SEED, ReferralAccount, ReferralMarketAccount};
 use crate::{
-    error::ProgramErrorCode, helper::transfer_token_or_point_to_pool,
+    constant::EVENT_SCHEMA_VERSION, error::ProgramErrorCode, events::BetPlaced,
+    helper::transfer_token_or_point_to_pool,
+    utils::{fee_discount_for, force_adjourn_deadline},
 };
 
 #[derive(Accounts)]
-#[instruction(answer_key: u64)]
+#[instruction(answer_key: u64, amount: u64, referrer: Option<Pubkey>)]
 pub struct Bet<'info> {
     #[account(mut)]
     pub voter: Signer<'info>,
     #[account(
         mut,
         seeds = [&CONFIG_SEED.as_bytes()],
-        bump = config_account.bump
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
     )]
     pub config_account: Account<'info, ConfigAccount>,
     /// Market account - moved before bet_mint so we can reference betting_token in constraint
     #[account(
       mut,
-      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = !market_account.frozen @ ProgramErrorCode::MarketFrozen
     )]
     pub market_account: Box<Account<'info, MarketAccount>>,
     /// Token mint - now validates against market's betting_token instead of global base_token
@@ -56,6 +64,35 @@ pub struct Bet<'info> {
       bump,
     )]
     pub bet_account: Box<Account<'info, BettingAccount>>,
+    /// Referrer's lifetime volume for this mint - only required when a referrer is given
+    #[account(
+      init_if_needed,
+      payer = voter,
+      space = 8 + ReferralAccount::INIT_SPACE,
+      seeds = [REFERRAL_SEED.as_bytes(), referrer.unwrap_or_default().as_ref(), bet_mint.key().as_ref()],
+      bump,
+    )]
+    pub referral_account: Option<Box<Account<'info, ReferralAccount>>>,
+    /// Referrer's volume in this market, used to split the market's referral fee
+    #[account(
+      init_if_needed,
+      payer = voter,
+      space = 8 + ReferralMarketAccount::INIT_SPACE,
+      seeds = [REFERRAL_MARKET_SEED.as_bytes(), referrer.unwrap_or_default().as_ref(), &market_account.market_key.to_le_bytes()],
+      bump,
+    )]
+    pub referral_market_account: Option<Box<Account<'info, ReferralMarketAccount>>>,
+    /// Voter's governance NFT stake - grants a service-fee discount tier when provided.
+    /// Only NFTs the voter staked count; votes delegated to it do not
+    #[account(
+      mut,
+      seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+      bump = voter_stake.bump,
+      seeds::program = boomplay_governance::ID,
+    )]
+    pub voter_stake: Option<Box<Account<'info, VoterStake>>>,
+    /// Locks `voter_stake` while the discount depends on it - required along with it
+    pub governance_program: Option<Program<'info, BoomplayGovernance>>,
 
     pub token_program: Program<'info, Token>,
 
@@ -64,15 +101,9 @@ pub struct Bet<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct BetPlaced {
-    pub voter: Pubkey,
-    pub market_key: Pubkey,
-    pub answer_key: u64,
-}
-
-pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64) -> Result<()> {
+pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64, referrer: Option<Pubkey>) -> Result<()> {
     let market_key = ctx.accounts.market_account.market_key.clone();
+    let market = ctx.accounts.market_account.key();
     let betting_account = ctx.accounts.bet_account.deref_mut();
     let market_account = ctx.accounts.market_account.deref_mut();
     let answer_account = ctx.accounts.answer_account.deref_mut();
@@ -105,15 +136,51 @@ pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64) -> Result<()> {
     }
 
     // Update the specific answer's total tokens
+    let mut answer_total_tokens: u64 = 0;
     for answer in answer_account.answers.iter_mut() {
         if answer.answer_key == answer_key {
             answer.answer_total_tokens += amount;
+            answer_total_tokens = answer.answer_total_tokens;
             break;
         }
     }
 
     let clock = Clock::get()?;
 
+    let fee_discount_percentage = match ctx.accounts.voter_stake.as_deref() {
+        Some(voter_stake) => fee_discount_for(&config_account.fee_discount_tiers, voter_stake.staked_count),
+        None => 0,
+    };
+    if fee_discount_percentage > 0 {
+        // The NFTs behind the discount stay staked until the market can no longer settle,
+        // so they can't be unstaked and restaked in another wallet to discount its bets too
+        let (Some(voter_stake), Some(governance_program)) = (
+            ctx.accounts.voter_stake.as_ref(),
+            ctx.accounts.governance_program.as_ref(),
+        ) else {
+            return Err(ProgramErrorCode::GovernanceProgramMissing.into());
+        };
+        let locked_until = force_adjourn_deadline(
+            market_account.status,
+            market_account.approve_time,
+            market_account.finish_time,
+            config_account.resolution_deadline,
+        )?;
+        boomplay_governance::cpi::lock_voter_stake(
+            CpiContext::new(
+                governance_program.to_account_info(),
+                LockVoterStake {
+                    voter: ctx.accounts.voter.to_account_info(),
+                    voter_stake: voter_stake.to_account_info(),
+                },
+            ),
+            locked_until as i64,
+        )?;
+    }
+    let fee_discount_weight = (amount as u128)
+        .checked_mul(fee_discount_percentage as u128)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
     betting_account.bump = ctx.bumps.bet_account;
     betting_account.market_key = market_key;
     betting_account.answer_key = answer_key;
@@ -121,14 +188,77 @@ pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64) -> Result<()> {
     betting_account.tokens += amount;
     betting_account.create_time = clock.unix_timestamp as u64;
     betting_account.exist = true;
+    betting_account.fee_discount_percentage = fee_discount_percentage;
+    betting_account.fee_discount_weight = betting_account
+        .fee_discount_weight
+        .checked_add(fee_discount_weight)
+        .ok_or(ProgramErrorCode::Overflow)?;
 
     market_account.market_total_tokens += amount;
     market_account.market_remain_tokens += amount;
+    market_account.market_fee_discount_weight = market_account
+        .market_fee_discount_weight
+        .checked_add(fee_discount_weight)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    if let Some(referrer) = referrer {
+        require!(referrer != ctx.accounts.voter.key(), ProgramErrorCode::InvalidReferrer);
+
+        let (Some(referral_account), Some(referral_market_account)) = (
+            ctx.accounts.referral_account.as_deref_mut(),
+            ctx.accounts.referral_market_account.as_deref_mut(),
+        ) else {
+            return Err(ProgramErrorCode::ReferralAccountsMissing.into());
+        };
+
+        if !referral_account.exist {
+            referral_account.bump = ctx.bumps.referral_account.unwrap_or_default();
+            referral_account.referrer = referrer;
+            referral_account.mint = ctx.accounts.bet_mint.key();
+            referral_account.exist = true;
+        }
+        referral_account.referred_tokens = referral_account
+            .referred_tokens
+            .checked_add(amount)
+            .ok_or(ProgramErrorCode::Overflow)?;
+        referral_account.referred_bets += 1;
+
+        if !referral_market_account.exist {
+            referral_market_account.bump = ctx.bumps.referral_market_account.unwrap_or_default();
+            referral_market_account.referrer = referrer;
+            referral_market_account.market_key = market_key;
+            referral_market_account.exist = true;
+        }
+        referral_market_account.referred_tokens = referral_market_account
+            .referred_tokens
+            .checked_add(amount)
+            .ok_or(ProgramErrorCode::Overflow)?;
+
+        market_account.market_referred_tokens = market_account
+            .market_referred_tokens
+            .checked_add(amount)
+            .ok_or(ProgramErrorCode::Overflow)?;
+    } else {
+        // Without a referrer the referral PDAs would be seeded by the default pubkey
+        require!(
+            ctx.accounts.referral_account.is_none() && ctx.accounts.referral_market_account.is_none(),
+            ProgramErrorCode::ReferralAccountsUnexpected
+        );
+    }
 
     emit!(BetPlaced {
+        version: EVENT_SCHEMA_VERSION,
         voter: ctx.accounts.voter.key(),
-        market_key: ctx.accounts.market_account.key(),
+        market_key,
+        market,
         answer_key,
+        amount,
+        referrer,
+        fee_discount_percentage,
+        bet_total_tokens: betting_account.tokens,
+        answer_total_tokens,
+        market_total_tokens: market_account.market_total_tokens,
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -0,0 +1,143 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::ReferralRewardClaimed,
+    helper::{assert_vault_covers_market, transfer_token_from_pool_to_user},
+    states::{
+        ConfigAccount, MarketAccount, MarketStatus, ReferralAccount, ReferralMarketAccount,
+        CONFIG_SEED, MARKET_SEED, REFERRAL_MARKET_SEED, REFERRAL_SEED,
+    },
+    utils::pro_rata_share,
+};
+
+#[derive(Accounts)]
+pub struct ClaimReferralReward<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Success @ ProgramErrorCode::MarketNotResolved,
+      constraint = !market_account.frozen @ ProgramErrorCode::MarketFrozen,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        constraint = market_account.betting_token == bet_mint.key() @ ProgramErrorCode::InvalidBetMint
+    )]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = referrer
+    )]
+    pub referrer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED.as_bytes(), referrer.key().as_ref(), bet_mint.key().as_ref()],
+        bump = referral_account.bump,
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_MARKET_SEED.as_bytes(), referrer.key().as_ref(), &market_account.market_key.to_le_bytes()],
+        bump = referral_market_account.bump,
+    )]
+    pub referral_market_account: Box<Account<'info, ReferralMarketAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays a referrer their share of a settled market's referral fee, pro-rata to the
+/// volume they referred into that market.
+pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+    let market_account = ctx.accounts.market_account.deref_mut();
+    let referral_account = ctx.accounts.referral_account.deref_mut();
+    let referral_market_account = ctx.accounts.referral_market_account.deref_mut();
+
+    require!(
+        !referral_market_account.claimed,
+        ProgramErrorCode::ReferralAlreadyClaimed
+    );
+    require!(
+        market_account.market_referred_tokens > 0,
+        ProgramErrorCode::NoReferralReward
+    );
+
+    // The referrer that completes the market's referred volume receives the exact remainder
+    let reward_tokens = pro_rata_share(
+        market_account.referral_fee,
+        market_account.referral_remain_tokens,
+        referral_market_account.referred_tokens as u128,
+        market_account.market_claimed_referred_tokens as u128,
+        market_account.market_referred_tokens as u128,
+    )
+    .ok_or(ProgramErrorCode::MathOperationError)?;
+
+    market_account.market_claimed_referred_tokens = market_account
+        .market_claimed_referred_tokens
+        .checked_add(referral_market_account.referred_tokens)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    market_account.referral_remain_tokens = market_account
+        .referral_remain_tokens
+        .checked_sub(reward_tokens)
+        .ok_or(ProgramErrorCode::InsufficientFunds)?;
+
+    referral_market_account.claimed = true;
+    referral_account.claimed_tokens = referral_account
+        .claimed_tokens
+        .checked_add(reward_tokens)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    if reward_tokens > 0 {
+        let seeds: &[&[u8]] = &[
+            MARKET_SEED.as_bytes(),
+            &market_account.market_key.to_le_bytes(),
+            &[market_account.bump],
+        ];
+        transfer_token_from_pool_to_user(
+            ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.referrer_token_account.to_account_info(),
+            ctx.accounts.market_account.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            &[seeds],
+            reward_tokens,
+        )?;
+
+        ctx.accounts.vault_token_account.reload()?;
+        assert_vault_covers_market(
+            &ctx.accounts.market_account,
+            ctx.accounts.vault_token_account.amount,
+        )?;
+    }
+
+    emit!(ReferralRewardClaimed {
+        version: EVENT_SCHEMA_VERSION,
+        referrer: ctx.accounts.referrer.key(),
+        market_key: ctx.accounts.market_account.market_key,
+        referred_tokens: ctx.accounts.referral_market_account.referred_tokens,
+        reward_tokens,
+        referral_remain_tokens: ctx.accounts.market_account.referral_remain_tokens,
+        claimed_tokens: ctx.accounts.referral_account.claimed_tokens,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
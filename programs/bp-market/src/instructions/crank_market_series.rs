@@ -0,0 +1,155 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{EVENT_SCHEMA_VERSION, MARKET_ACCOUNT_VERSION},
+    error::ProgramErrorCode,
+    events::{MarketPublished, SeriesMarketCreated},
+    helper::{open_market, MarketParams},
+    states::{
+        AnswerAccount, ConfigAccount, MarketAccount, MarketSeries, MarketTemplate, ANSWER_SEED,
+        CONFIG_SEED, MARKET_SEED, MARKET_SERIES_SEED, MARKET_TEMPLATE_SEED, MAX_SERIES_EPOCH,
+    },
+    utils::{series_market_key, series_market_title},
+};
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct CrankMarketSeries<'info> {
+    /// Anyone may open a due epoch, paying the rent of the new market
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        mut,
+        seeds = [MARKET_SERIES_SEED.as_bytes(), &market_series.series_key.to_le_bytes()],
+        bump = market_series.bump,
+        constraint = market_series.active @ ProgramErrorCode::SeriesInactive
+    )]
+    pub market_series: Account<'info, MarketSeries>,
+    #[account(
+        seeds = [MARKET_TEMPLATE_SEED.as_bytes(), &market_series.template_key.to_le_bytes()],
+        bump = market_template.bump,
+    )]
+    pub market_template: Account<'info, MarketTemplate>,
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + MarketAccount::INIT_SPACE,
+        seeds = [MARKET_SEED.as_bytes(), &series_market_key(market_series.series_key, epoch).to_le_bytes()],
+        bump,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + AnswerAccount::INIT_SPACE,
+        seeds = [ANSWER_SEED.as_bytes(), &series_market_key(market_series.series_key, epoch).to_le_bytes()],
+        bump,
+    )]
+    pub answer_account: Box<Account<'info, AnswerAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the market of the series' current epoch from its template.
+/// Epochs whose window passed without a crank are skipped.
+pub fn crank_market_series(ctx: Context<CrankMarketSeries>, epoch: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+    let market_series = &ctx.accounts.market_series;
+
+    require!(epoch <= MAX_SERIES_EPOCH, ProgramErrorCode::InvalidSeriesEpoch);
+    require!(
+        market_series.max_epochs == 0 || epoch < market_series.max_epochs,
+        ProgramErrorCode::SeriesCompleted
+    );
+    require!(epoch >= market_series.next_epoch, ProgramErrorCode::InvalidSeriesEpoch);
+
+    // Only the epoch whose window contains `now` may be opened
+    let epoch_start = epoch
+        .checked_mul(market_series.interval)
+        .and_then(|offset| offset.checked_add(market_series.start_time))
+        .ok_or(ProgramErrorCode::Overflow)?;
+    let epoch_end = epoch_start
+        .checked_add(market_series.interval)
+        .ok_or(ProgramErrorCode::Overflow)?;
+    require!(
+        now >= epoch_start && now < epoch_end,
+        ProgramErrorCode::InvalidSeriesEpoch
+    );
+
+    let market_template = &ctx.accounts.market_template;
+    let market_key = series_market_key(market_series.series_key, epoch);
+    let params = MarketParams {
+        creator: market_template.creator,
+        title: series_market_title(&market_template.title_pattern, epoch),
+        betting_token: market_template.betting_token,
+        create_fee: market_template.create_fee,
+        creator_fee_percentage: market_template.creator_fee_percentage,
+        service_fee_percentage: market_template.service_fee_percentage,
+        charity_fee_percentage: market_template.charity_fee_percentage,
+        answer_keys: market_template.answer_keys.clone(),
+    };
+    let referral_fee_percentage = ctx.accounts.config_account.referral_fee_percentage;
+
+    let series = ctx.accounts.market_series.key();
+    let previous_market_key = ctx.accounts.market_series.last_market_key;
+
+    let market_account = ctx.accounts.market_account.deref_mut();
+    let answer_account = ctx.accounts.answer_account.deref_mut();
+
+    open_market(
+        market_account,
+        answer_account,
+        market_key,
+        &params,
+        referral_fee_percentage,
+        clock.unix_timestamp,
+    )?;
+    market_account.bump = ctx.bumps.market_account;
+    market_account.version = MARKET_ACCOUNT_VERSION;
+    market_account.series = series;
+    market_account.series_epoch = epoch;
+    market_account.previous_market_key = previous_market_key;
+    answer_account.bump = ctx.bumps.answer_account;
+
+    let market_series = &mut ctx.accounts.market_series;
+    market_series.next_epoch = epoch + 1;
+    market_series.last_market_key = market_key;
+    market_series.market_count += 1;
+
+    emit!(MarketPublished {
+        version: EVENT_SCHEMA_VERSION,
+        market_key,
+        market: ctx.accounts.market_account.key(),
+        creator: params.creator,
+        betting_token: params.betting_token,
+        title: params.title,
+        create_fee: params.create_fee,
+        creator_fee_percentage: params.creator_fee_percentage,
+        service_fee_percentage: params.service_fee_percentage,
+        charity_fee_percentage: params.charity_fee_percentage,
+        referral_fee_percentage,
+        answer_keys: params.answer_keys,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(SeriesMarketCreated {
+        version: EVENT_SCHEMA_VERSION,
+        series_key: market_series.series_key,
+        series,
+        epoch,
+        market_key,
+        previous_market_key,
+        cranked_by: ctx.accounts.cranker.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketFinished,
+    states::{
+        MarketAccount, MarketSeries, MarketStatus, MarketTemplate, MARKET_SERIES_SEED,
+        MARKET_TEMPLATE_SEED,
+    },
+};
+
+#[derive(Accounts)]
+pub struct FinishSeriesMarket<'info> {
+    /// Anyone may close betting once the template's duration has elapsed
+    pub caller: Signer<'info>,
+    #[account(
+        seeds = [MARKET_SERIES_SEED.as_bytes(), &market_series.series_key.to_le_bytes()],
+        bump = market_series.bump,
+    )]
+    pub market_series: Account<'info, MarketSeries>,
+    #[account(
+        seeds = [MARKET_TEMPLATE_SEED.as_bytes(), &market_series.template_key.to_le_bytes()],
+        bump = market_template.bump,
+    )]
+    pub market_template: Account<'info, MarketTemplate>,
+    #[account(
+      mut,
+      constraint = market_account.series == market_series.key() @ ProgramErrorCode::NotSeriesMarket,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+}
+
+/// Finishes a series market whose betting window is over. Resolution stays with the owner.
+pub fn finish_series_market(ctx: Context<FinishSeriesMarket>) -> Result<()> {
+    let duration = ctx.accounts.market_template.duration;
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    let clock = Clock::get()?;
+
+    let close_time = market_account
+        .approve_time
+        .checked_add(duration)
+        .ok_or(ProgramErrorCode::Overflow)?;
+    require!(
+        clock.unix_timestamp as u64 >= close_time,
+        ProgramErrorCode::SeriesMarketStillOpen
+    );
+
+    market_account.status = MarketStatus::Finished;
+    market_account.finish_time = clock.unix_timestamp as u64;
+    market_account.market_remain_tokens = market_account.market_total_tokens;
+
+    emit!(MarketFinished {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        market_total_tokens: market_account.market_total_tokens,
+        market_remain_tokens: market_account.market_remain_tokens,
+        finished_by: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketForceAdjourned,
+    states::{ConfigAccount, MarketAccount, MarketStatus, CONFIG_SEED},
+    utils::force_adjourn_deadline,
+};
+
+#[derive(Accounts)]
+pub struct ForceAdjournMarket<'info> {
+    /// Anyone may trigger the emergency adjournment once the deadline has passed
+    pub caller: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+      mut,
+      constraint = matches!(
+          market_account.status,
+          MarketStatus::Approve | MarketStatus::Finished
+      ) @ ProgramErrorCode::MarketNotFinished
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+}
+
+/// Adjourns a market that the owner never resolved, or never finished, so every bettor
+/// can reclaim 100% of their stake through receive_token.
+pub fn force_adjourn_market(ctx: Context<ForceAdjournMarket>) -> Result<()> {
+    let resolution_deadline = ctx.accounts.config_account.resolution_deadline;
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    let clock = Clock::get()?;
+
+    let deadline = force_adjourn_deadline(
+        market_account.status,
+        market_account.approve_time,
+        market_account.finish_time,
+        resolution_deadline,
+    )?;
+    require!(
+        clock.unix_timestamp as u64 > deadline,
+        ProgramErrorCode::ResolutionDeadlineNotReached
+    );
+
+    market_account.status = MarketStatus::Adjourn;
+    market_account.adjourn_time = clock.unix_timestamp as u64;
+
+    emit!(MarketForceAdjourned {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        refundable_tokens: market_account.market_remain_tokens,
+        adjourned_by: ctx.accounts.caller.key(),
+        finish_time: market_account.finish_time,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
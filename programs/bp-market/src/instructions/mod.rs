@@ -1,7 +1,23 @@
 pub mod owner;
 pub mod bet;
 pub mod receive_token;
+pub mod claim_referral_reward;
+pub mod force_adjourn_market;
+pub mod place_parlay;
+pub mod settle_parlay;
+pub mod crank_market_series;
+pub mod finish_series_market;
+pub mod seed_liquidity;
+pub mod refund_seed_liquidity;
 
 pub use owner::*;
 pub use bet::*;
-pub use receive_token::*;
\ No newline at end of file
+pub use receive_token::*;
+pub use claim_referral_reward::*;
+pub use force_adjourn_market::*;
+pub use place_parlay::*;
+pub use settle_parlay::*;
+pub use crank_market_series::*;
+pub use finish_series_market::*;
+pub use seed_liquidity::*;
+pub use refund_seed_liquidity::*;
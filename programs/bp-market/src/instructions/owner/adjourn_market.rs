@@ -2,7 +2,12 @@ use std::ops::DerefMut;
 
 use anchor_lang::prelude::*;
 
-use crate::{error::ProgramErrorCode, states::{ConfigAccount, MarketAccount, MarketStatus}};
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketAdjourned,
+    states::{ConfigAccount, MarketAccount, MarketStatus},
+};
 
 #[derive(Accounts)]
 pub struct AdjournMarket<'info> {
@@ -21,11 +26,6 @@ pub struct AdjournMarket<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct MarketAdjourned {
-    pub market_key: u64,
-}
-
 pub fn adjourn_market(ctx: Context<AdjournMarket>) -> Result<()> {
     let market_account = ctx.accounts.market_account.deref_mut();
 
@@ -35,7 +35,11 @@ pub fn adjourn_market(ctx: Context<AdjournMarket>) -> Result<()> {
     market_account.adjourn_time = clock.unix_timestamp as u64;
 
     emit!(MarketAdjourned {
-        market_key: market_account.market_key.clone(),
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        refundable_tokens: market_account.market_remain_tokens,
+        adjourned_by: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -0,0 +1,83 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketSeriesCreated,
+    states::{
+        ConfigAccount, MarketSeries, MarketTemplate, CONFIG_SEED, MARKET_SERIES_SEED,
+        MARKET_TEMPLATE_SEED, MAX_SERIES_EPOCH, MAX_SERIES_KEY,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(series_key: u64)]
+pub struct CreateMarketSeries<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        seeds = [MARKET_TEMPLATE_SEED.as_bytes(), &market_template.template_key.to_le_bytes()],
+        bump = market_template.bump,
+    )]
+    pub market_template: Account<'info, MarketTemplate>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MarketSeries::INIT_SPACE,
+        seeds = [MARKET_SERIES_SEED.as_bytes(), &series_key.to_le_bytes()],
+        bump,
+    )]
+    pub market_series: Account<'info, MarketSeries>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Schedules a market from `market_template` every `interval` seconds from `start_time`.
+/// The series starts active; `max_epochs` of 0 keeps it running until it is deactivated.
+pub fn create_market_series(
+    ctx: Context<CreateMarketSeries>,
+    series_key: u64,
+    start_time: u64,
+    interval: u64,
+    max_epochs: u64,
+) -> Result<()> {
+    require!(series_key <= MAX_SERIES_KEY, ProgramErrorCode::InvalidSeriesKey);
+    require!(interval > 0, ProgramErrorCode::InvalidSeriesSchedule);
+    require!(max_epochs <= MAX_SERIES_EPOCH, ProgramErrorCode::InvalidSeriesSchedule);
+
+    let series = ctx.accounts.market_series.key();
+    let template_key = ctx.accounts.market_template.template_key;
+    let market_series = ctx.accounts.market_series.deref_mut();
+
+    market_series.bump = ctx.bumps.market_series;
+    market_series.series_key = series_key;
+    market_series.template_key = template_key;
+    market_series.start_time = start_time;
+    market_series.interval = interval;
+    market_series.max_epochs = max_epochs;
+    market_series.active = true;
+    market_series.exist = true;
+
+    emit!(MarketSeriesCreated {
+        version: EVENT_SCHEMA_VERSION,
+        series_key,
+        series,
+        template_key,
+        start_time,
+        interval,
+        max_epochs,
+        created_by: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
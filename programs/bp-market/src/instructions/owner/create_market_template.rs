@@ -0,0 +1,91 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketTemplateCreated,
+    helper::{validate_answer_keys, MarketParams},
+    states::{ConfigAccount, MarketTemplate, CONFIG_SEED, MARKET_TEMPLATE_SEED, MAX_TITLE_LEN},
+};
+
+#[derive(Accounts)]
+#[instruction(template_key: u64)]
+pub struct CreateMarketTemplate<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MarketTemplate::INIT_SPACE,
+        seeds = [MARKET_TEMPLATE_SEED.as_bytes(), &template_key.to_le_bytes()],
+        bump,
+    )]
+    pub market_template: Account<'info, MarketTemplate>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_market_template(
+    ctx: Context<CreateMarketTemplate>,
+    template_key: u64,
+    params: MarketParams,
+    duration: u64,
+) -> Result<()> {
+    let MarketParams {
+        creator,
+        title: title_pattern,
+        betting_token,
+        create_fee,
+        creator_fee_percentage,
+        service_fee_percentage,
+        charity_fee_percentage,
+        answer_keys,
+    } = params;
+    validate_answer_keys(&answer_keys)?;
+    require!(title_pattern.len() <= MAX_TITLE_LEN, ProgramErrorCode::TitleTooLong);
+    require!(duration > 0, ProgramErrorCode::InvalidSeriesSchedule);
+
+    let template = ctx.accounts.market_template.key();
+    let market_template = ctx.accounts.market_template.deref_mut();
+
+    market_template.bump = ctx.bumps.market_template;
+    market_template.template_key = template_key;
+    market_template.creator = creator;
+    market_template.title_pattern = title_pattern.clone();
+    market_template.betting_token = betting_token;
+    market_template.create_fee = create_fee;
+    market_template.creator_fee_percentage = creator_fee_percentage;
+    market_template.service_fee_percentage = service_fee_percentage;
+    market_template.charity_fee_percentage = charity_fee_percentage;
+    market_template.answer_keys = answer_keys.clone();
+    market_template.duration = duration;
+    market_template.exist = true;
+
+    emit!(MarketTemplateCreated {
+        version: EVENT_SCHEMA_VERSION,
+        template_key,
+        template,
+        creator,
+        title_pattern,
+        betting_token,
+        create_fee,
+        creator_fee_percentage,
+        service_fee_percentage,
+        charity_fee_percentage,
+        answer_keys,
+        duration,
+        created_by: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -2,7 +2,12 @@ use std::ops::DerefMut;
 
 use anchor_lang::prelude::*;
 
-use crate::{error::ProgramErrorCode, states::{ConfigAccount, MarketAccount, MarketStatus}};
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketFinished,
+    states::{ConfigAccount, MarketAccount, MarketStatus},
+};
 
 #[derive(Accounts)]
 pub struct FinishMarket<'info> {
@@ -21,11 +26,6 @@ pub struct FinishMarket<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct MarketFinished {
-    pub market_key: u64,
-}
-
 pub fn finish_market(ctx: Context<FinishMarket>) -> Result<()> {
     let market_account = ctx.accounts.market_account.deref_mut();
 
@@ -36,7 +36,12 @@ pub fn finish_market(ctx: Context<FinishMarket>) -> Result<()> {
     market_account.market_remain_tokens = market_account.market_total_tokens;
 
     emit!(MarketFinished {
-        market_key: market_account.market_key.clone(),
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        market_total_tokens: market_account.market_total_tokens,
+        market_remain_tokens: market_account.market_remain_tokens,
+        finished_by: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -0,0 +1,59 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::{MarketFrozen, MarketUnfrozen},
+    states::{ConfigAccount, MarketAccount, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct FreezeMarket<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(mut)]
+    pub market_account: Account<'info, MarketAccount>,
+}
+
+/// Freezes or unfreezes a single market. A frozen market rejects bets and claims
+/// but can still be finished, resolved or adjourned by the owner.
+pub fn freeze_market(ctx: Context<FreezeMarket>, frozen: bool) -> Result<()> {
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    require!(
+        market_account.frozen != frozen,
+        ProgramErrorCode::MarketFreezeUnchanged
+    );
+
+    market_account.frozen = frozen;
+
+    let clock = Clock::get()?;
+
+    if frozen {
+        emit!(MarketFrozen {
+            version: EVENT_SCHEMA_VERSION,
+            market_key: market_account.market_key,
+            frozen_by: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    } else {
+        emit!(MarketUnfrozen {
+            version: EVENT_SCHEMA_VERSION,
+            market_key: market_account.market_key,
+            unfrozen_by: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -3,6 +3,11 @@ use std::ops::DerefMut;
 use anchor_lang::prelude::*;
 
 use crate::{
+    constant::{
+        CONFIG_ACCOUNT_VERSION, DEFAULT_MIN_PARLAY_OUTCOME_STAKE, DEFAULT_RESOLUTION_DEADLINE,
+        EVENT_SCHEMA_VERSION,
+    },
+    events::ConfigInitialized,
     states::{ConfigAccount, CONFIG_SEED},
 };
 
@@ -13,7 +18,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = owner,
-        space = ConfigAccount::INIT_SPACE,
+        space = 8 + ConfigAccount::INIT_SPACE,
         seeds = [&CONFIG_SEED.as_bytes()],
         bump
     )]
@@ -37,7 +42,24 @@ pub fn initialize(
     config_account.cojam_fee_account = cojam_fee_account;
     config_account.charity_fee_account = charity_fee_account;
     config_account.remain_account = remain_account;
+    config_account.referral_fee_percentage = 0;
     config_account.locked_users = Vec::new();
+    config_account.fee_discount_tiers = Vec::new();
+    config_account.paused = false;
+    config_account.resolution_deadline = DEFAULT_RESOLUTION_DEADLINE;
+    config_account.min_parlay_outcome_stake = DEFAULT_MIN_PARLAY_OUTCOME_STAKE;
+    config_account.version = CONFIG_ACCOUNT_VERSION;
+
+    emit!(ConfigInitialized {
+        version: EVENT_SCHEMA_VERSION,
+        owner: config_account.owner,
+        base_token,
+        cojam_fee_account,
+        charity_fee_account,
+        remain_account,
+        resolution_deadline: config_account.resolution_deadline,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
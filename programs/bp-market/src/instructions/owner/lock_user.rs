@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::ProgramErrorCode, 
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::UserLocked,
     states::{ConfigAccount, CONFIG_SEED, MAX_LOCKED_USERS}
 };
 
@@ -36,7 +38,13 @@ pub fn lock_user(ctx: Context<LockUser>, user_to_lock: Pubkey) -> Result<()> {
     
     config_account.locked_users.push(user_to_lock);
     
-    msg!("User locked: {:?}", user_to_lock);
+    emit!(UserLocked {
+        version: EVENT_SCHEMA_VERSION,
+        user: user_to_lock,
+        locked_by: ctx.accounts.owner.key(),
+        locked_user_count: config_account.locked_users.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 }
\ No newline at end of file
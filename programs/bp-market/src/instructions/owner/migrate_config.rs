@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{CONFIG_ACCOUNT_VERSION, EVENT_SCHEMA_VERSION},
+    error::ProgramErrorCode,
+    events::ConfigMigrated,
+    helper::realloc_account,
+    states::{ConfigAccount, ConfigAccountV0, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: May still use an older layout, so it is deserialized by hand after the owner and seeds checks
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites the config account in the current layout, growing it if needed
+/// and filling fields added since its version with their defaults.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config_account.to_account_info();
+    let new_len = 8 + ConfigAccount::INIT_SPACE;
+
+    let mut config_account = {
+        let data = config_info.try_borrow_data()?;
+        if data.len() < new_len {
+            require!(
+                data.len() >= 8 && data[..8] == *ConfigAccount::DISCRIMINATOR,
+                ProgramErrorCode::InvalidAccountData
+            );
+            ConfigAccount::from(ConfigAccountV0::deserialize(&mut &data[8..])?)
+        } else {
+            ConfigAccount::try_deserialize(&mut &data[..])?
+        }
+    };
+
+    require!(
+        config_account.owner == ctx.accounts.owner.key(),
+        ProgramErrorCode::Unauthorized
+    );
+
+    let from_version = config_account.version;
+    require!(
+        from_version < CONFIG_ACCOUNT_VERSION,
+        ProgramErrorCode::AccountAlreadyMigrated
+    );
+
+    config_account.version = CONFIG_ACCOUNT_VERSION;
+
+    realloc_account(
+        config_info.clone(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    {
+        let mut data = config_info.try_borrow_mut_data()?;
+        config_account.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(ConfigMigrated {
+        version: EVENT_SCHEMA_VERSION,
+        from_version,
+        to_version: CONFIG_ACCOUNT_VERSION,
+        migrated_by: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{MARKET_ACCOUNT_VERSION, EVENT_SCHEMA_VERSION},
+    error::ProgramErrorCode,
+    events::MarketMigrated,
+    helper::realloc_account,
+    states::{ConfigAccount, MarketAccount, MarketAccountV0, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    /// CHECK: May still use an older layout, so it is deserialized by hand after the owner check
+    #[account(mut, owner = crate::ID)]
+    pub market_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites a market account in the current layout, growing it if needed
+/// and filling fields added since its version with their defaults.
+pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+    let market_info = ctx.accounts.market_account.to_account_info();
+    let new_len = 8 + MarketAccount::INIT_SPACE;
+
+    let mut market_account = {
+        let data = market_info.try_borrow_data()?;
+        if data.len() < new_len {
+            require!(
+                data.len() >= 8 && data[..8] == *MarketAccount::DISCRIMINATOR,
+                ProgramErrorCode::InvalidAccountData
+            );
+            MarketAccount::from(MarketAccountV0::deserialize(&mut &data[8..])?)
+        } else {
+            MarketAccount::try_deserialize(&mut &data[..])?
+        }
+    };
+
+    let from_version = market_account.version;
+    require!(
+        from_version < MARKET_ACCOUNT_VERSION,
+        ProgramErrorCode::AccountAlreadyMigrated
+    );
+
+    market_account.version = MARKET_ACCOUNT_VERSION;
+
+    realloc_account(
+        market_info.clone(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    {
+        let mut data = market_info.try_borrow_mut_data()?;
+        market_account.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(MarketMigrated {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        from_version,
+        to_version: MARKET_ACCOUNT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
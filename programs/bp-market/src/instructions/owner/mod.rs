@@ -9,6 +9,18 @@ pub mod unlock_user;
 pub mod set_account;
 pub mod set_base_token;
 pub mod retrieve_tokens;
+pub mod set_referral_fee;
+pub mod set_fee_discount_tiers;
+pub mod pause;
+pub mod freeze_market;
+pub mod set_resolution_deadline;
+pub mod set_min_parlay_outcome_stake;
+pub mod void_market;
+pub mod migrate_config;
+pub mod migrate_market;
+pub mod create_market_template;
+pub mod create_market_series;
+pub mod set_market_series_active;
 
 pub use publish_market::*;
 pub use update_owner::*;
@@ -20,4 +32,16 @@ pub use lock_user::*;
 pub use unlock_user::*;
 pub use set_account::*;
 pub use set_base_token::*;
-pub use retrieve_tokens::*;
\ No newline at end of file
+pub use retrieve_tokens::*;
+pub use set_referral_fee::*;
+pub use set_fee_discount_tiers::*;
+pub use pause::*;
+pub use freeze_market::*;
+pub use set_resolution_deadline::*;
+pub use set_min_parlay_outcome_stake::*;
+pub use void_market::*;
+pub use migrate_config::*;
+pub use migrate_market::*;
+pub use create_market_template::*;
+pub use create_market_series::*;
+pub use set_market_series_active::*;
\ No newline at end of file
@@ -0,0 +1,54 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::{MarketsPaused, MarketsUnpaused},
+    states::{ConfigAccount, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+}
+
+pub fn pause(ctx: Context<Pause>, paused: bool) -> Result<()> {
+    let config_account = ctx.accounts.config_account.deref_mut();
+
+    require!(
+        config_account.paused != paused,
+        ProgramErrorCode::PauseStateUnchanged
+    );
+
+    config_account.paused = paused;
+
+    let clock = Clock::get()?;
+
+    if paused {
+        emit!(MarketsPaused {
+            version: EVENT_SCHEMA_VERSION,
+            paused_by: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    } else {
+        emit!(MarketsUnpaused {
+            version: EVENT_SCHEMA_VERSION,
+            unpaused_by: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
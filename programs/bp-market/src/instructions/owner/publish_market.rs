@@ -3,7 +3,11 @@ use std::ops::DerefMut;
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::ProgramErrorCode, states::{CONFIG_SEED, Answer, AnswerAccount, ConfigAccount, MarketAccount, MarketStatus, ANSWER_SEED, MARKET_SEED, MAX_ANWSER}
+    constant::{MARKET_ACCOUNT_VERSION, EVENT_SCHEMA_VERSION},
+    error::ProgramErrorCode,
+    events::MarketPublished,
+    helper::{open_market, MarketParams},
+    states::{CONFIG_SEED, AnswerAccount, ConfigAccount, MarketAccount, ANSWER_SEED, MARKET_SEED}
 };
 
 #[derive(Accounts)]
@@ -18,12 +22,13 @@ pub struct PublishMarket<'info> {
         mut,
         seeds = [&CONFIG_SEED.as_bytes()],
         bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
     )]
     pub config_account: Account<'info, ConfigAccount>,
     #[account(
         init,
         payer = owner,
-        space = MarketAccount::INIT_SPACE,
+        space = 8 + MarketAccount::INIT_SPACE,
         seeds = [MARKET_SEED.as_bytes(), &market_key.to_le_bytes()],
         bump,
     )]
@@ -40,19 +45,6 @@ pub struct PublishMarket<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct MarketPublished {
-    pub creator: Pubkey,
-    pub market_key: u64,
-    pub betting_token: Pubkey,  // Token mint for this market
-    pub title: String,
-    pub create_fee: u64,
-    pub creator_fee_percentage: u64,
-    pub service_fee_percentage: u64,
-    pub charity_fee_percentage: u64,
-    pub answer_keys: Vec<u64>,
-}
-
 pub fn publish_market(
     ctx: Context<PublishMarket>,
     market_key: u64,
@@ -65,65 +57,47 @@ pub fn publish_market(
     charity_fee_percentage: u64,
     answer_keys: Vec<u64>,
 ) -> Result<()> {
-    // Validate answer keys
-    if answer_keys.is_empty() {
-        return Err(ProgramErrorCode::NoAnswersProvided.into());
-    }
-    
-    if answer_keys.len() > MAX_ANWSER {
-        return Err(ProgramErrorCode::MaxAnswersReached.into());
-    }
-    
-    // Check for duplicate answer keys
-    let mut unique_keys = answer_keys.clone();
-    unique_keys.sort();
-    unique_keys.dedup();
-    if unique_keys.len() != answer_keys.len() {
-        return Err(ProgramErrorCode::AnswerAlreadyExists.into());
-    }
+    let params = MarketParams {
+        creator,
+        title: title.clone(),
+        betting_token,
+        create_fee,
+        creator_fee_percentage,
+        service_fee_percentage,
+        charity_fee_percentage,
+        answer_keys: answer_keys.clone(),
+    };
+    let clock = Clock::get()?;
 
     let market_account = ctx.accounts.market_account.deref_mut();
     let answer_account = ctx.accounts.answer_account.deref_mut();
-    
-    // Draft market
-    market_account.bump = ctx.bumps.market_account;
-    market_account.creator = creator;
-    market_account.market_key = market_key;
-    market_account.betting_token = betting_token;  // Save betting token for this market
-    market_account.title = title.clone();
-    market_account.creator_fee = create_fee;
-    market_account.creator_fee_percentage = creator_fee_percentage;
-    market_account.service_fee_percentage = service_fee_percentage;
-    market_account.charity_fee_percentage = charity_fee_percentage;
-
-    //approve market
-    let clock = Clock::get()?;
-
-    market_account.status = MarketStatus::Approve;
-    market_account.approve_time = clock.unix_timestamp as u64;
 
-    // Add answer keys
+    open_market(
+        market_account,
+        answer_account,
+        market_key,
+        &params,
+        ctx.accounts.config_account.referral_fee_percentage,
+        clock.unix_timestamp,
+    )?;
+    market_account.bump = ctx.bumps.market_account;
+    market_account.version = MARKET_ACCOUNT_VERSION;
     answer_account.bump = ctx.bumps.answer_account;
-    answer_account.answers = Vec::with_capacity(MAX_ANWSER);
-    answer_account.exist = true;
-    
-    for answer_key in &answer_keys {
-        answer_account.answers.push(Answer {
-            answer_key: *answer_key,
-            answer_total_tokens: 0,
-        });
-    }
 
     emit!(MarketPublished {
-        creator,
+        version: EVENT_SCHEMA_VERSION,
         market_key,
+        market: ctx.accounts.market_account.key(),
+        creator,
         betting_token,
         title,
         create_fee,
         creator_fee_percentage,
         service_fee_percentage,
         charity_fee_percentage,
+        referral_fee_percentage: ctx.accounts.market_account.referral_fee_percentage,
         answer_keys,
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -5,9 +5,11 @@ use anchor_spl::{
 };
 
 use crate::{
+    constant::EVENT_SCHEMA_VERSION,
     error::ProgramErrorCode,
-    helper::{is_retrieve_available, transfer_token_from_pool_to_user},
-    states::{ConfigAccount, MarketAccount, MARKET_SEED},
+    events::TokensRetrieved,
+    helper::{assert_vault_covers_market, is_retrieve_available, transfer_token_from_pool_to_user},
+    states::{ConfigAccount, MarketAccount, MarketStatus, MARKET_SEED},
 };
 
 #[derive(Accounts)]
@@ -38,7 +40,19 @@ pub fn retrieve_tokens(ctx: Context<RetrieveTokens>) -> Result<()> {
     let can_retrieve = is_retrieve_available(market_account, &clock)?;
     require!(can_retrieve, ProgramErrorCode::CannotRetrieveBeforeDate);
 
-    let remains_amount = market_account.market_remain_tokens;
+    // Seed liquidity is already part of market_remain_tokens on success, otherwise it is
+    // whatever the creator did not take back with refund_seed_liquidity
+    let unrefunded_seed_tokens = if market_account.status == MarketStatus::Success {
+        0
+    } else {
+        market_account.seed_liquidity_tokens
+    };
+
+    // Unclaimed referral rewards and fee rebates expire together with unclaimed winnings.
+    // The whole vault is swept, so tokens sent to it outside the market's accounting
+    // cannot keep it from draining
+    assert_vault_covers_market(market_account, ctx.accounts.vault_token_account.amount)?;
+    let retrieved_tokens = ctx.accounts.vault_token_account.amount;
 
     let seeds: &[&[u8]] = &[
         MARKET_SEED.as_bytes(),
@@ -51,11 +65,34 @@ pub fn retrieve_tokens(ctx: Context<RetrieveTokens>) -> Result<()> {
         ctx.accounts.remains_token_account.to_account_info(),
         market_account.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
-        &[&seeds],
-        remains_amount,
+        &[seeds],
+        retrieved_tokens,
     )?;
 
+    emit!(TokensRetrieved {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        destination: ctx.accounts.remains_token_account.key(),
+        retrieved_by: ctx.accounts.owner.key(),
+        unclaimed_winning_tokens: market_account.market_remain_tokens,
+        unclaimed_referral_tokens: market_account.referral_remain_tokens,
+        unclaimed_fee_discount_tokens: market_account.fee_discount_remain_tokens,
+        retrieved_tokens,
+        timestamp: clock.unix_timestamp,
+    });
+
     market_account.market_remain_tokens = 0;
+    market_account.referral_remain_tokens = 0;
+    market_account.fee_discount_remain_tokens = 0;
+    if unrefunded_seed_tokens > 0 {
+        market_account.seed_liquidity_tokens = 0;
+    }
+
+    ctx.accounts.vault_token_account.reload()?;
+    assert_vault_covers_market(
+        &ctx.accounts.market_account,
+        ctx.accounts.vault_token_account.amount,
+    )?;
 
     Ok(())
 }
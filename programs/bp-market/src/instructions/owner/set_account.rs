@@ -3,7 +3,9 @@ use std::ops::DerefMut;
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::ProgramErrorCode, 
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::AccountUpdated,
     states::{ConfigAccount, CONFIG_SEED}
 };
 
@@ -30,13 +32,6 @@ pub struct SetAccount<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct AccountUpdated {
-    pub account_type: AccountType,
-    pub old_account: Pubkey,
-    pub new_account: Pubkey,
-}
-
 pub fn set_account(
     ctx: Context<SetAccount>, 
     account_type: AccountType,
@@ -63,9 +58,12 @@ pub fn set_account(
     };
     
     emit!(AccountUpdated {
+        version: EVENT_SCHEMA_VERSION,
         account_type,
         old_account,
         new_account,
+        updated_by: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
     });
         
     Ok(())
@@ -3,7 +3,9 @@ use std::ops::DerefMut;
 use anchor_lang::prelude::*;
 
 use crate::{
+    constant::EVENT_SCHEMA_VERSION,
     error::ProgramErrorCode,
+    events::BaseTokenUpdated,
     states::{ConfigAccount, CONFIG_SEED},
 };
 
@@ -23,14 +25,6 @@ pub struct SetBaseToken<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct BaseTokenUpdated {
-    pub old_token: Pubkey,
-    pub new_token: Pubkey,
-    pub updated_by: Pubkey,
-    pub timestamp: i64,
-}
-
 pub fn set_base_token(ctx: Context<SetBaseToken>, new_base_token: Pubkey) -> Result<()> {
     let config_account = ctx.accounts.config_account.deref_mut();
     let old_token = config_account.base_token;
@@ -40,6 +34,7 @@ pub fn set_base_token(ctx: Context<SetBaseToken>, new_base_token: Pubkey) -> Res
     let clock = Clock::get()?;
 
     emit!(BaseTokenUpdated {
+        version: EVENT_SCHEMA_VERSION,
         old_token,
         new_token: new_base_token,
         updated_by: ctx.accounts.owner.key(),
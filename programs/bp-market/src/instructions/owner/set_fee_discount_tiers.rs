@@ -0,0 +1,66 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{BASIS_POINTS, EVENT_SCHEMA_VERSION},
+    error::ProgramErrorCode,
+    events::FeeDiscountTiersUpdated,
+    states::{ConfigAccount, FeeDiscountTier, CONFIG_SEED, MAX_FEE_DISCOUNT_TIERS},
+};
+
+#[derive(Accounts)]
+pub struct SetFeeDiscountTiers<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the governance NFT fee-discount tiers. Tiers must be strictly increasing
+/// in `min_nfts` and never decrease in discount. Existing bets keep the tier recorded
+/// on their `BettingAccount`.
+pub fn set_fee_discount_tiers(
+    ctx: Context<SetFeeDiscountTiers>,
+    tiers: Vec<FeeDiscountTier>,
+) -> Result<()> {
+    require!(
+        tiers.len() <= MAX_FEE_DISCOUNT_TIERS,
+        ProgramErrorCode::InvalidFeeDiscountTiers
+    );
+    require!(
+        tiers
+            .iter()
+            .all(|tier| tier.min_nfts > 0 && tier.discount_percentage <= BASIS_POINTS as u64),
+        ProgramErrorCode::InvalidFeeDiscountTiers
+    );
+    require!(
+        tiers.windows(2).all(|pair| {
+            pair[0].min_nfts < pair[1].min_nfts
+                && pair[0].discount_percentage <= pair[1].discount_percentage
+        }),
+        ProgramErrorCode::InvalidFeeDiscountTiers
+    );
+
+    let config_account = ctx.accounts.config_account.deref_mut();
+    config_account.fee_discount_tiers = tiers.clone();
+
+    let clock = Clock::get()?;
+
+    emit!(FeeDiscountTiersUpdated {
+        version: EVENT_SCHEMA_VERSION,
+        tiers,
+        updated_by: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketSeriesActiveSet,
+    states::{ConfigAccount, MarketSeries, CONFIG_SEED, MARKET_SERIES_SEED},
+};
+
+#[derive(Accounts)]
+pub struct SetMarketSeriesActive<'info> {
+    #[account(
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        mut,
+        seeds = [MARKET_SERIES_SEED.as_bytes(), &market_series.series_key.to_le_bytes()],
+        bump = market_series.bump,
+    )]
+    pub market_series: Account<'info, MarketSeries>,
+}
+
+/// Stops or resumes a series. Markets it already opened are not affected.
+pub fn set_market_series_active(ctx: Context<SetMarketSeriesActive>, active: bool) -> Result<()> {
+    let market_series = &mut ctx.accounts.market_series;
+
+    require!(
+        market_series.active != active,
+        ProgramErrorCode::SeriesStateUnchanged
+    );
+
+    market_series.active = active;
+
+    emit!(MarketSeriesActiveSet {
+        version: EVENT_SCHEMA_VERSION,
+        series_key: market_series.series_key,
+        active,
+        updated_by: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MinParlayOutcomeStakeUpdated,
+    states::{ConfigAccount, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct SetMinParlayOutcomeStake<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+}
+
+pub fn set_min_parlay_outcome_stake(
+    ctx: Context<SetMinParlayOutcomeStake>,
+    min_parlay_outcome_stake: u64,
+) -> Result<()> {
+    require!(
+        min_parlay_outcome_stake > 0,
+        ProgramErrorCode::InvalidMinParlayOutcomeStake
+    );
+
+    let config_account = ctx.accounts.config_account.deref_mut();
+    let old_min_parlay_outcome_stake = config_account.min_parlay_outcome_stake;
+
+    config_account.min_parlay_outcome_stake = min_parlay_outcome_stake;
+
+    let clock = Clock::get()?;
+
+    emit!(MinParlayOutcomeStakeUpdated {
+        version: EVENT_SCHEMA_VERSION,
+        old_min_parlay_outcome_stake,
+        new_min_parlay_outcome_stake: min_parlay_outcome_stake,
+        updated_by: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{BASIS_POINTS, EVENT_SCHEMA_VERSION},
+    error::ProgramErrorCode,
+    events::ReferralFeeUpdated,
+    states::{ConfigAccount, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct SetReferralFee<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the share of each market's service fee (in basis points) that is paid
+/// out to referrers. Applies to markets published after the change.
+pub fn set_referral_fee(ctx: Context<SetReferralFee>, referral_fee_percentage: u64) -> Result<()> {
+    require!(
+        referral_fee_percentage <= BASIS_POINTS as u64,
+        ProgramErrorCode::InvalidReferralFee
+    );
+
+    let config_account = ctx.accounts.config_account.deref_mut();
+    let old_referral_fee_percentage = config_account.referral_fee_percentage;
+
+    config_account.referral_fee_percentage = referral_fee_percentage;
+
+    let clock = Clock::get()?;
+
+    emit!(ReferralFeeUpdated {
+        version: EVENT_SCHEMA_VERSION,
+        old_referral_fee_percentage,
+        new_referral_fee_percentage: referral_fee_percentage,
+        updated_by: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::ResolutionDeadlineUpdated,
+    states::{ConfigAccount, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct SetResolutionDeadline<'info> {
+    #[account(
+        mut,
+        constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+}
+
+pub fn set_resolution_deadline(
+    ctx: Context<SetResolutionDeadline>,
+    resolution_deadline: u64,
+) -> Result<()> {
+    require!(
+        resolution_deadline > 0,
+        ProgramErrorCode::InvalidResolutionDeadline
+    );
+
+    let config_account = ctx.accounts.config_account.deref_mut();
+    let old_resolution_deadline = config_account.resolution_deadline;
+
+    config_account.resolution_deadline = resolution_deadline;
+
+    let clock = Clock::get()?;
+
+    emit!(ResolutionDeadlineUpdated {
+        version: EVENT_SCHEMA_VERSION,
+        old_resolution_deadline,
+        new_resolution_deadline: resolution_deadline,
+        updated_by: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
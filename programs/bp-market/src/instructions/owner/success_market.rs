@@ -4,7 +4,11 @@ use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::states::{ANSWER_SEED, CONFIG_SEED, AnswerAccount, ConfigAccount, MarketAccount, MarketStatus, MARKET_SEED};
 use crate::{
-    constant::BASIS_POINTS, error::ProgramErrorCode, helper::transfer_token_from_pool_to_user,
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketSuccess,
+    helper::transfer_token_from_pool_to_user,
+    utils::{calculate_market_fees, MarketFees},
 };
 
 #[derive(Accounts)]
@@ -63,70 +67,6 @@ pub struct SuccessMarket<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-#[event]
-pub struct MarketSuccess {
-    pub market_key: u64,
-    pub answer_key: u64,
-    pub creator_fee: u64,
-    pub service_fee: u64,
-    pub market_remain_tokens: u64,
-}
-struct MarketFees {
-    creator_fee: u64,
-    service_fee: u64,
-    charity_fee: u64,
-}
-
-fn calculate_market_fees(market_account: &mut MarketAccount) -> Result<MarketFees> {
-    let remain_tokens = market_account.market_remain_tokens as u128;
-
-    let creator_fee_percentage = market_account.creator_fee_percentage as u128;
-    let service_fee_percentage = market_account.service_fee_percentage as u128;
-    let charity_fee_percentage = market_account.charity_fee_percentage as u128;
-
-    let old_creator_fee = market_account.creator_fee as u128;
-
-    let additional_creator_fee = remain_tokens
-        .checked_mul(creator_fee_percentage)
-        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
-        .ok_or(ProgramErrorCode::Overflow)?;
-
-    let total_creator_fee = old_creator_fee
-        .checked_add(additional_creator_fee)
-        .ok_or(ProgramErrorCode::Overflow)?;
-
-    let service_fee = remain_tokens
-        .checked_mul(service_fee_percentage)
-        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
-        .ok_or(ProgramErrorCode::Overflow)?;
-
-    let charity_fee = remain_tokens
-        .checked_mul(charity_fee_percentage)
-        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
-        .ok_or(ProgramErrorCode::Overflow)?;
-
-    let remaining_tokens = remain_tokens
-        .checked_sub(additional_creator_fee)
-        .and_then(|result| result.checked_sub(service_fee))
-        .and_then(|result| result.checked_sub(charity_fee))
-        .ok_or(ProgramErrorCode::Overflow)?;
-
-    // Update market_reward_base_tokens
-    market_account.market_reward_base_tokens = remaining_tokens as u64;
-    
-    // Update creator_fee to include the additional fee
-    market_account.creator_fee = total_creator_fee as u64;
-
-    // Update market_remain_tokens (subtract the fees that were taken out)
-    market_account.market_remain_tokens = remaining_tokens as u64;
-
-    Ok(MarketFees {
-        creator_fee: total_creator_fee as u64,
-        service_fee: service_fee as u64,
-        charity_fee: charity_fee as u64,
-    })
-}
-
 pub fn success_market(ctx: Context<SuccessMarket>, correct_answer_key: u64) -> Result<()> {
     let fees: MarketFees = {
         let market_account = &mut ctx.accounts.market_account;
@@ -183,11 +123,18 @@ pub fn success_market(ctx: Context<SuccessMarket>, correct_answer_key: u64) -> R
     )?;
 
     emit!(MarketSuccess {
+        version: EVENT_SCHEMA_VERSION,
         market_key: ctx.accounts.market_account.market_key,
         answer_key: correct_answer_key,
         creator_fee: fees.creator_fee,
         service_fee: fees.service_fee,
+        charity_fee: fees.charity_fee,
+        referral_fee: fees.referral_fee,
+        fee_discount: fees.fee_discount,
+        market_total_tokens: ctx.accounts.market_account.market_total_tokens,
+        market_reward_base_tokens: ctx.accounts.market_account.market_reward_base_tokens,
         market_remain_tokens: ctx.accounts.market_account.market_remain_tokens,
+        timestamp: ctx.accounts.market_account.success_time as i64,
     });
 
     Ok(())
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::ProgramErrorCode, 
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::UserUnlocked,
     states::{ConfigAccount, CONFIG_SEED}
 };
 
@@ -31,7 +33,13 @@ pub fn unlock_user(ctx: Context<UnlockUser>, user_to_unlock: Pubkey) -> Result<(
     
     config_account.locked_users.remove(user_index);
     
-    msg!("User unlocked: {:?}", user_to_unlock);
+    emit!(UserUnlocked {
+        version: EVENT_SCHEMA_VERSION,
+        user: user_to_unlock,
+        unlocked_by: ctx.accounts.owner.key(),
+        locked_user_count: config_account.locked_users.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
 use std::ops::DerefMut;
 
 use crate::{
+    constant::EVENT_SCHEMA_VERSION,
     error::ProgramErrorCode,
+    events::OwnerUpdated,
     states::{ConfigAccount, CONFIG_SEED},
 };
 use anchor_lang::prelude::*;
@@ -23,8 +25,16 @@ pub struct UpdateOwner<'info> {
 
 pub fn update_owner(ctx: Context<UpdateOwner>, new_owner: Pubkey) -> Result<()> {
     let config_account = ctx.accounts.config_account.deref_mut();
+    let old_owner = config_account.owner;
 
     config_account.owner = new_owner;
 
+    emit!(OwnerUpdated {
+        version: EVENT_SCHEMA_VERSION,
+        old_owner,
+        new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
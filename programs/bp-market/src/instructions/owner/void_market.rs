@@ -0,0 +1,54 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::MarketVoided,
+    states::{ConfigAccount, MarketAccount, MarketStatus, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct VoidMarket<'info> {
+    #[account(
+      mut,
+      constraint = (owner.key() == config_account.owner) @ ProgramErrorCode::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved
+    )]
+    pub market_account: Account<'info, MarketAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Voids a market that is still open for betting (e.g. wrong title or answer set).
+/// Bets stop immediately and every bettor can reclaim their full stake.
+pub fn void_market(ctx: Context<VoidMarket>, reason: u8) -> Result<()> {
+    let market_account = ctx.accounts.market_account.deref_mut();
+
+    let clock = Clock::get()?;
+
+    market_account.status = MarketStatus::Voided;
+    market_account.void_time = clock.unix_timestamp as u64;
+    market_account.void_reason = reason;
+    market_account.market_remain_tokens = market_account.market_total_tokens;
+
+    emit!(MarketVoided {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        reason,
+        voided_by: ctx.accounts.owner.key(),
+        refundable_tokens: market_account.market_total_tokens,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
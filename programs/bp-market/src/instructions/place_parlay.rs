@@ -0,0 +1,219 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::ParlayPlaced,
+    helper::{load_leg_answers, load_leg_market, transfer_token_or_point_to_pool},
+    states::{
+        ConfigAccount, MarketStatus, ParlayAccount, ParlayLeg, ParlayLegResult, ParlayOutcome,
+        ParlayPoolAccount, CONFIG_SEED, MAX_PARLAY_LEGS, MAX_PARLAY_OUTCOMES, MIN_PARLAY_LEGS,
+        PARLAY_POOL_SEED, PARLAY_SEED,
+    },
+    utils::parlay_pool_id,
+};
+
+#[derive(Accounts)]
+#[instruction(parlay_key: u64, pool_id: [u8; 32])]
+pub struct PlaceParlay<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + ParlayPoolAccount::INIT_SPACE,
+        seeds = [PARLAY_POOL_SEED.as_bytes(), &pool_id],
+        bump,
+    )]
+    pub parlay_pool: Box<Account<'info, ParlayPoolAccount>>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + ParlayAccount::INIT_SPACE,
+        seeds = [PARLAY_SEED.as_bytes(), voter.key().as_ref(), &parlay_key.to_le_bytes()],
+        bump,
+    )]
+    pub parlay_account: Box<Account<'info, ParlayAccount>>,
+    /// Every leg's market must bet in this mint
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = bet_mint,
+        associated_token::authority = parlay_pool
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: (market_account, answer_account) for each leg, in market_keys order
+}
+
+pub fn place_parlay(
+    ctx: Context<PlaceParlay>,
+    parlay_key: u64,
+    pool_id: [u8; 32],
+    market_keys: Vec<u64>,
+    answer_keys: Vec<u64>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, ProgramErrorCode::InvalidBetAmount);
+    require!(
+        (MIN_PARLAY_LEGS..=MAX_PARLAY_LEGS).contains(&market_keys.len())
+            && answer_keys.len() == market_keys.len(),
+        ProgramErrorCode::InvalidParlayLegCount
+    );
+    // Ascending keys give every combination of markets a single pool
+    require!(
+        market_keys.windows(2).all(|pair| pair[0] < pair[1]),
+        ProgramErrorCode::InvalidParlayLegOrder
+    );
+    require!(
+        parlay_pool_id(&market_keys) == pool_id,
+        ProgramErrorCode::InvalidParlayPool
+    );
+    require!(
+        !ctx.accounts.config_account.locked_users.contains(&ctx.accounts.voter.key()),
+        ProgramErrorCode::UserAlreadyLocked
+    );
+
+    let leg_accounts = ctx.remaining_accounts;
+    require!(
+        leg_accounts.len() == market_keys.len() * 2,
+        ProgramErrorCode::InvalidParlayLegAccount
+    );
+
+    let bet_mint = ctx.accounts.bet_mint.key();
+    let mut legs = Vec::with_capacity(market_keys.len());
+    for (i, (market_key, answer_key)) in market_keys.iter().zip(&answer_keys).enumerate() {
+        let market_info = &leg_accounts[i * 2];
+        let market_account = load_leg_market(market_info, *market_key)?;
+        require!(
+            market_account.status == MarketStatus::Approve,
+            ProgramErrorCode::MarketNotApproved
+        );
+        require!(!market_account.frozen, ProgramErrorCode::MarketFrozen);
+        require_keys_eq!(
+            market_account.betting_token,
+            bet_mint,
+            ProgramErrorCode::InvalidBetMint
+        );
+
+        let answer_account = load_leg_answers(&leg_accounts[i * 2 + 1], *market_key)?;
+        require!(
+            answer_account
+                .answers
+                .iter()
+                .any(|answer| answer.answer_key == *answer_key),
+            ProgramErrorCode::AnswerNotExists
+        );
+
+        legs.push(ParlayLeg {
+            market: market_info.key(),
+            market_key: *market_key,
+            answer_key: *answer_key,
+        });
+    }
+
+    transfer_token_or_point_to_pool(
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.voter.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    let clock = Clock::get()?;
+    let pool = ctx.accounts.parlay_pool.key();
+    let parlay_pool = ctx.accounts.parlay_pool.deref_mut();
+
+    if !parlay_pool.exist {
+        parlay_pool.bump = ctx.bumps.parlay_pool;
+        parlay_pool.pool_id = pool_id;
+        parlay_pool.mint = bet_mint;
+        parlay_pool.market_keys = market_keys.clone();
+        parlay_pool.outcomes = Vec::with_capacity(MAX_PARLAY_OUTCOMES);
+        parlay_pool.leg_results = vec![ParlayLegResult::Pending; market_keys.len()];
+        parlay_pool.create_time = clock.unix_timestamp as u64;
+        parlay_pool.exist = true;
+    }
+    require!(!parlay_pool.settled, ProgramErrorCode::ParlayPoolAlreadySettled);
+    require_keys_eq!(parlay_pool.mint, bet_mint, ProgramErrorCode::InvalidBetMint);
+
+    let outcome_stake = match parlay_pool
+        .outcomes
+        .iter_mut()
+        .find(|outcome| outcome.answer_keys == answer_keys)
+    {
+        Some(outcome) => {
+            outcome.stake = outcome
+                .stake
+                .checked_add(amount)
+                .ok_or(ProgramErrorCode::Overflow)?;
+            outcome.stake
+        }
+        None => {
+            require!(
+                parlay_pool.outcomes.len() < MAX_PARLAY_OUTCOMES,
+                ProgramErrorCode::MaxParlayOutcomesReached
+            );
+            require!(
+                amount >= ctx.accounts.config_account.min_parlay_outcome_stake,
+                ProgramErrorCode::ParlayOutcomeStakeTooLow
+            );
+            parlay_pool.outcomes.push(ParlayOutcome {
+                answer_keys: answer_keys.clone(),
+                stake: amount,
+            });
+            amount
+        }
+    };
+
+    parlay_pool.total_stake = parlay_pool
+        .total_stake
+        .checked_add(amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+    parlay_pool.remain_tokens = parlay_pool
+        .remain_tokens
+        .checked_add(amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let parlay_account = ctx.accounts.parlay_account.deref_mut();
+    parlay_account.bump = ctx.bumps.parlay_account;
+    parlay_account.voter = ctx.accounts.voter.key();
+    parlay_account.parlay_key = parlay_key;
+    parlay_account.pool = pool;
+    parlay_account.legs = legs.clone();
+    parlay_account.stake = amount;
+    parlay_account.create_time = clock.unix_timestamp as u64;
+    parlay_account.exist = true;
+
+    emit!(ParlayPlaced {
+        version: EVENT_SCHEMA_VERSION,
+        voter: ctx.accounts.voter.key(),
+        parlay_key,
+        pool,
+        legs,
+        amount,
+        outcome_stake,
+        pool_total_stake: parlay_pool.total_stake,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -6,13 +6,15 @@ use anchor_spl::{associated_token::AssociatedToken, token::Token};
 
 use crate::states::BETTING_SEED;
 use crate::{
-    constant::MAX_PERCENTAGE_BASIS_POINTS,
+    constant::EVENT_SCHEMA_VERSION,
     error::ProgramErrorCode,
-    helper::transfer_token_from_pool_to_user,
+    events::TokenReceived,
+    helper::{assert_vault_covers_market, transfer_token_from_pool_to_user},
     states::{
         AnswerAccount, BettingAccount, ConfigAccount, MarketAccount, MarketStatus, CONFIG_SEED,
         MARKET_SEED,
     },
+    utils::pro_rata_share,
 };
 
 #[derive(Accounts)]
@@ -22,13 +24,17 @@ pub struct ReceiveToken<'info> {
     #[account(
         mut,
         seeds = [&CONFIG_SEED.as_bytes()],
-        bump = config_account.bump
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
     )]
     pub config_account: Account<'info, ConfigAccount>,
     /// Market account - moved before bet_mint so we can reference betting_token in constraint
     #[account(
       mut,
-      constraint = market_account.status == MarketStatus::Success || market_account.status == MarketStatus::Adjourn @ ProgramErrorCode::CannotClaimToken,
+      constraint = market_account.status == MarketStatus::Success
+        || market_account.status == MarketStatus::Adjourn
+        || market_account.status == MarketStatus::Voided @ ProgramErrorCode::CannotClaimToken,
+      constraint = !market_account.frozen @ ProgramErrorCode::MarketFrozen,
     )]
     pub market_account: Box<Account<'info, MarketAccount>>,
     /// Token mint - now validates against market's betting_token instead of global base_token
@@ -62,14 +68,6 @@ pub struct ReceiveToken<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[event]
-pub struct TokenReceived {
-    pub receiver: Pubkey,
-    pub market_key: u64,
-    pub betting_key: u64,
-    pub received_tokens: u64,
-}
-
 pub fn receive_token(ctx: Context<ReceiveToken>) -> Result<()> {
     let market_account = ctx.accounts.market_account.deref_mut();
     let betting_account = &mut ctx.accounts.bet_account;
@@ -79,7 +77,7 @@ pub fn receive_token(ctx: Context<ReceiveToken>) -> Result<()> {
     let betting_tokens = betting_account.tokens as u128;
     let answer_key = betting_account.answer_key;
 
-    let mut percentage = 0;
+    let mut receive_tokens: u64 = 0;
 
     if market_account.status == MarketStatus::Success
         && betting_account.answer_key == correct_answer_key
@@ -92,13 +90,24 @@ pub fn receive_token(ctx: Context<ReceiveToken>) -> Result<()> {
             }
         }
 
-        let market_reward_base_tokens = market_account.market_reward_base_tokens as u128;
-        percentage = market_reward_base_tokens
-            .checked_mul(MAX_PERCENTAGE_BASIS_POINTS)
-            .and_then(|result| result.checked_div(correct_answer_total_tokens))
-            .ok_or(ProgramErrorCode::MathOperationError)?;
-    } else if market_account.status == MarketStatus::Adjourn {
-        percentage = MAX_PERCENTAGE_BASIS_POINTS;
+        // The winner that completes the correct answer's stake receives the exact remainder
+        receive_tokens = pro_rata_share(
+            market_account.market_reward_base_tokens,
+            market_account.market_remain_tokens,
+            betting_tokens,
+            market_account.market_claimed_winning_tokens as u128,
+            correct_answer_total_tokens,
+        )
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+        market_account.market_claimed_winning_tokens = market_account
+            .market_claimed_winning_tokens
+            .checked_add(betting_tokens as u64)
+            .ok_or(ProgramErrorCode::Overflow)?;
+    } else if market_account.status == MarketStatus::Adjourn
+        || market_account.status == MarketStatus::Voided
+    {
+        receive_tokens = betting_tokens as u64;
         let answer_exists = answer_account
             .answers
             .iter()
@@ -109,14 +118,39 @@ pub fn receive_token(ctx: Context<ReceiveToken>) -> Result<()> {
         }
     }
 
-    let receive_tokens = betting_tokens
-        .checked_mul(percentage)
-        .and_then(|result| result.checked_div(MAX_PERCENTAGE_BASIS_POINTS))
+    //dividend token to user
+    market_account.market_remain_tokens = market_account
+        .market_remain_tokens
+        .checked_sub(receive_tokens)
+        .ok_or(ProgramErrorCode::InsufficientFunds)?;
+
+    // Governance NFT holders get their service-fee rebate whether or not they won
+    let mut fee_rebate_tokens: u64 = 0;
+    if market_account.status == MarketStatus::Success
+        && market_account.market_fee_discount_weight > 0
+    {
+        fee_rebate_tokens = pro_rata_share(
+            market_account.fee_discount_tokens,
+            market_account.fee_discount_remain_tokens,
+            betting_account.fee_discount_weight,
+            market_account.market_claimed_fee_discount_weight,
+            market_account.market_fee_discount_weight,
+        )
         .ok_or(ProgramErrorCode::MathOperationError)?;
 
-    //dividend token to user
-    market_account.market_remain_tokens =
-        market_account.market_remain_tokens - receive_tokens as u64;
+        market_account.market_claimed_fee_discount_weight = market_account
+            .market_claimed_fee_discount_weight
+            .checked_add(betting_account.fee_discount_weight)
+            .ok_or(ProgramErrorCode::Overflow)?;
+        market_account.fee_discount_remain_tokens = market_account
+            .fee_discount_remain_tokens
+            .checked_sub(fee_rebate_tokens)
+            .ok_or(ProgramErrorCode::InsufficientFunds)?;
+    }
+
+    let receive_tokens = receive_tokens
+        .checked_add(fee_rebate_tokens)
+        .ok_or(ProgramErrorCode::MathOperationError)?;
 
     if receive_tokens > 0 {
         let bet_seeds: &[&[u8]] = &[
@@ -130,15 +164,26 @@ pub fn receive_token(ctx: Context<ReceiveToken>) -> Result<()> {
             ctx.accounts.market_account.to_account_info(),
             ctx.accounts.token_program.to_account_info(),
             &[&bet_seeds],
-            receive_tokens as u64,
+            receive_tokens,
+        )?;
+
+        ctx.accounts.vault_bet_token_account.reload()?;
+        assert_vault_covers_market(
+            &ctx.accounts.market_account,
+            ctx.accounts.vault_bet_token_account.amount,
         )?;
-        
 
         emit!(TokenReceived {
+            version: EVENT_SCHEMA_VERSION,
             receiver: ctx.accounts.voter.key(),
             market_key: ctx.accounts.market_account.market_key,
-            betting_key: betting_account.answer_key,
-            received_tokens: receive_tokens as u64,
+            answer_key,
+            bet_tokens: betting_account.tokens,
+            received_tokens: receive_tokens,
+            fee_rebate_tokens,
+            market_remain_tokens: ctx.accounts.market_account.market_remain_tokens,
+            fee_discount_remain_tokens: ctx.accounts.market_account.fee_discount_remain_tokens,
+            timestamp: Clock::get()?.unix_timestamp,
         });
     }
 
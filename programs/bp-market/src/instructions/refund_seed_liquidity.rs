@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::SeedLiquidityRefunded,
+    helper::{assert_vault_covers_market, transfer_token_from_pool_to_user},
+    states::{ConfigAccount, MarketAccount, MarketStatus, CONFIG_SEED, MARKET_SEED},
+};
+
+#[derive(Accounts)]
+pub struct RefundSeedLiquidity<'info> {
+    #[account(
+        mut,
+        constraint = (creator.key() == market_account.creator) @ ProgramErrorCode::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Adjourn
+        || market_account.status == MarketStatus::Voided @ ProgramErrorCode::SeedLiquidityNotRefundable,
+      constraint = !market_account.frozen @ ProgramErrorCode::MarketFrozen,
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        constraint = market_account.betting_token == bet_mint.key() @ ProgramErrorCode::InvalidBetMint
+    )]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Returns the creator's seed liquidity once the market is adjourned or voided.
+pub fn refund_seed_liquidity(ctx: Context<RefundSeedLiquidity>) -> Result<()> {
+    let amount = ctx.accounts.market_account.seed_liquidity_tokens;
+    require!(amount > 0, ProgramErrorCode::NoSeedLiquidity);
+
+    ctx.accounts.market_account.seed_liquidity_tokens = 0;
+
+    let market_seeds: &[&[u8]] = &[
+        MARKET_SEED.as_bytes(),
+        &ctx.accounts.market_account.market_key.to_le_bytes(),
+        &[ctx.accounts.market_account.bump],
+    ];
+    transfer_token_from_pool_to_user(
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.creator_token_account.to_account_info(),
+        ctx.accounts.market_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        &[market_seeds],
+        amount,
+    )?;
+
+    ctx.accounts.vault_token_account.reload()?;
+    assert_vault_covers_market(
+        &ctx.accounts.market_account,
+        ctx.accounts.vault_token_account.amount,
+    )?;
+
+    emit!(SeedLiquidityRefunded {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: ctx.accounts.market_account.market_key,
+        creator: ctx.accounts.creator.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
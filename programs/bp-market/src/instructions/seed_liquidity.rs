@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::SeedLiquidityAdded,
+    helper::transfer_token_or_point_to_pool,
+    states::{ConfigAccount, MarketAccount, MarketStatus, CONFIG_SEED},
+};
+
+#[derive(Accounts)]
+pub struct SeedLiquidity<'info> {
+    #[account(
+        mut,
+        constraint = (creator.key() == market_account.creator) @ ProgramErrorCode::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+      mut,
+      constraint = market_account.status == MarketStatus::Approve @ ProgramErrorCode::MarketNotApproved,
+      constraint = !market_account.frozen @ ProgramErrorCode::MarketFrozen
+    )]
+    pub market_account: Box<Account<'info, MarketAccount>>,
+    #[account(
+        constraint = market_account.betting_token == bet_mint.key() @ ProgramErrorCode::InvalidBetMint
+    )]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = bet_mint,
+        associated_token::authority = market_account
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits a subsidy from the market's creator into its vault. It belongs to no answer:
+/// success_market adds it to the winners' reward base, and the creator takes it back
+/// with refund_seed_liquidity if the market is adjourned or voided.
+pub fn seed_liquidity(ctx: Context<SeedLiquidity>, amount: u64) -> Result<()> {
+    require!(amount > 0, ProgramErrorCode::InvalidBetAmount);
+
+    transfer_token_or_point_to_pool(
+        ctx.accounts.creator_token_account.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    let market_account = &mut ctx.accounts.market_account;
+    market_account.seed_liquidity_tokens = market_account
+        .seed_liquidity_tokens
+        .checked_add(amount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    emit!(SeedLiquidityAdded {
+        version: EVENT_SCHEMA_VERSION,
+        market_key: market_account.market_key,
+        creator: ctx.accounts.creator.key(),
+        amount,
+        seed_liquidity_tokens: market_account.seed_liquidity_tokens,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use std::ops::DerefMut;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    constant::EVENT_SCHEMA_VERSION,
+    error::ProgramErrorCode,
+    events::{ParlayPoolSettled, ParlaySettled},
+    helper::{load_leg_market, transfer_token_from_pool_to_user},
+    states::{
+        ConfigAccount, MarketStatus, ParlayAccount, ParlayLegResult, ParlayPoolAccount,
+        ParlaySettlement, CONFIG_SEED, PARLAY_POOL_SEED, PARLAY_SEED,
+    },
+    utils::pro_rata_share,
+};
+
+#[derive(Accounts)]
+pub struct SettleParlay<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [&CONFIG_SEED.as_bytes()],
+        bump = config_account.bump,
+        constraint = !config_account.paused @ ProgramErrorCode::ProgramPaused
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+    #[account(
+        mut,
+        seeds = [PARLAY_POOL_SEED.as_bytes(), &parlay_pool.pool_id],
+        bump = parlay_pool.bump,
+    )]
+    pub parlay_pool: Box<Account<'info, ParlayPoolAccount>>,
+    #[account(
+        mut,
+        seeds = [PARLAY_SEED.as_bytes(), voter.key().as_ref(), &parlay_account.parlay_key.to_le_bytes()],
+        bump = parlay_account.bump,
+        constraint = parlay_account.pool == parlay_pool.key() @ ProgramErrorCode::InvalidParlayPool,
+        close = voter
+    )]
+    pub parlay_account: Box<Account<'info, ParlayAccount>>,
+    #[account(
+        constraint = parlay_pool.mint == bet_mint.key() @ ProgramErrorCode::InvalidBetMint
+    )]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = parlay_pool
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: each leg's market_account in market_keys order, only read by the
+    // first settlement of the pool
+}
+
+pub fn settle_parlay(ctx: Context<SettleParlay>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool = ctx.accounts.parlay_pool.key();
+    let parlay_pool = ctx.accounts.parlay_pool.deref_mut();
+    let parlay_account = &ctx.accounts.parlay_account;
+
+    // The first parlay settled records every leg's result for the whole pool
+    if !parlay_pool.settled {
+        let leg_accounts = ctx.remaining_accounts;
+        require!(
+            leg_accounts.len() == parlay_pool.market_keys.len(),
+            ProgramErrorCode::InvalidParlayLegAccount
+        );
+
+        for (i, market_info) in leg_accounts.iter().enumerate() {
+            let market_account = load_leg_market(market_info, parlay_pool.market_keys[i])?;
+            require!(!market_account.frozen, ProgramErrorCode::MarketFrozen);
+
+            parlay_pool.leg_results[i] = match market_account.status {
+                MarketStatus::Success => ParlayLegResult::Correct {
+                    answer_key: market_account.correct_answer_key,
+                },
+                MarketStatus::Adjourn | MarketStatus::Voided => ParlayLegResult::Void,
+                _ => return Err(ProgramErrorCode::ParlayLegNotResolved.into()),
+            };
+        }
+
+        let mut winning_stake: u64 = 0;
+        for outcome in &parlay_pool.outcomes {
+            if parlay_pool.is_winning(&outcome.answer_keys) {
+                winning_stake = winning_stake
+                    .checked_add(outcome.stake)
+                    .ok_or(ProgramErrorCode::Overflow)?;
+            }
+        }
+
+        parlay_pool.winning_stake = winning_stake;
+        parlay_pool.settled = true;
+        parlay_pool.settle_time = clock.unix_timestamp as u64;
+
+        emit!(ParlayPoolSettled {
+            version: EVENT_SCHEMA_VERSION,
+            pool,
+            market_keys: parlay_pool.market_keys.clone(),
+            leg_results: parlay_pool.leg_results.clone(),
+            total_stake: parlay_pool.total_stake,
+            winning_stake,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let answer_keys: Vec<u64> = parlay_account.legs.iter().map(|leg| leg.answer_key).collect();
+    let stake = parlay_account.stake;
+
+    let (settlement, payout) = if parlay_pool.winning_stake == 0 {
+        (ParlaySettlement::Refunded, stake)
+    } else if parlay_pool.is_winning(&answer_keys) {
+        // The winner that completes the winning stake receives the exact remainder
+        let payout = pro_rata_share(
+            parlay_pool.total_stake,
+            parlay_pool.remain_tokens,
+            stake as u128,
+            parlay_pool.claimed_winning_stake as u128,
+            parlay_pool.winning_stake as u128,
+        )
+        .ok_or(ProgramErrorCode::MathOperationError)?;
+
+        parlay_pool.claimed_winning_stake = parlay_pool
+            .claimed_winning_stake
+            .checked_add(stake)
+            .ok_or(ProgramErrorCode::Overflow)?;
+
+        (ParlaySettlement::Won, payout)
+    } else {
+        (ParlaySettlement::Forfeited, 0)
+    };
+
+    parlay_pool.remain_tokens = parlay_pool
+        .remain_tokens
+        .checked_sub(payout)
+        .ok_or(ProgramErrorCode::InsufficientFunds)?;
+
+    if payout > 0 {
+        let pool_seeds: &[&[u8]] = &[
+            PARLAY_POOL_SEED.as_bytes(),
+            &ctx.accounts.parlay_pool.pool_id,
+            &[ctx.accounts.parlay_pool.bump],
+        ];
+        transfer_token_from_pool_to_user(
+            ctx.accounts.vault_token_account.to_account_info(),
+            ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.parlay_pool.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            &[pool_seeds],
+            payout,
+        )?;
+    }
+
+    emit!(ParlaySettled {
+        version: EVENT_SCHEMA_VERSION,
+        voter: ctx.accounts.voter.key(),
+        parlay_key: parlay_account.parlay_key,
+        pool,
+        stake,
+        settlement,
+        payout,
+        pool_remain_tokens: ctx.accounts.parlay_pool.remain_tokens,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -11,7 +11,9 @@ pub mod instructions;
 pub mod states;
 pub mod utils;
 
+use helper::MarketParams;
 use instructions::*;
+use states::FeeDiscountTier;
 
 #[program]
 pub mod bp_market {
@@ -69,8 +71,13 @@ pub mod bp_market {
         instructions::finish_market(ctx)
     }
 
-    pub fn bet(ctx: Context<Bet>, answer_key: u64, amount: u64) -> Result<()> {
-        instructions::bet(ctx, answer_key, amount)
+    pub fn bet(
+        ctx: Context<Bet>,
+        answer_key: u64,
+        amount: u64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::bet(ctx, answer_key, amount, referrer)
     }
 
     pub fn lock_user(ctx: Context<LockUser>, user_to_lock: Pubkey) -> Result<()> {
@@ -93,6 +100,17 @@ pub mod bp_market {
         instructions::set_base_token(ctx, new_base_token)
     }
 
+    pub fn set_referral_fee(ctx: Context<SetReferralFee>, referral_fee_percentage: u64) -> Result<()> {
+        instructions::set_referral_fee(ctx, referral_fee_percentage)
+    }
+
+    pub fn set_fee_discount_tiers(
+        ctx: Context<SetFeeDiscountTiers>,
+        tiers: Vec<FeeDiscountTier>,
+    ) -> Result<()> {
+        instructions::set_fee_discount_tiers(ctx, tiers)
+    }
+
     pub fn receive_token(ctx: Context<ReceiveToken>) -> Result<()> {
         instructions::receive_token(ctx)
     }
@@ -100,4 +118,100 @@ pub mod bp_market {
     pub fn retrieve_tokens(ctx: Context<RetrieveTokens>) -> Result<()> {
         instructions::retrieve_tokens(ctx)
     }
+
+    pub fn pause(ctx: Context<Pause>, paused: bool) -> Result<()> {
+        instructions::pause(ctx, paused)
+    }
+
+    pub fn freeze_market(ctx: Context<FreezeMarket>, frozen: bool) -> Result<()> {
+        instructions::freeze_market(ctx, frozen)
+    }
+
+    pub fn void_market(ctx: Context<VoidMarket>, reason: u8) -> Result<()> {
+        instructions::void_market(ctx, reason)
+    }
+
+    pub fn set_resolution_deadline(
+        ctx: Context<SetResolutionDeadline>,
+        resolution_deadline: u64,
+    ) -> Result<()> {
+        instructions::set_resolution_deadline(ctx, resolution_deadline)
+    }
+
+    pub fn set_min_parlay_outcome_stake(
+        ctx: Context<SetMinParlayOutcomeStake>,
+        min_parlay_outcome_stake: u64,
+    ) -> Result<()> {
+        instructions::set_min_parlay_outcome_stake(ctx, min_parlay_outcome_stake)
+    }
+
+    pub fn force_adjourn_market(ctx: Context<ForceAdjournMarket>) -> Result<()> {
+        instructions::force_adjourn_market(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config(ctx)
+    }
+
+    pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+        instructions::migrate_market(ctx)
+    }
+
+    pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+        instructions::claim_referral_reward(ctx)
+    }
+
+    pub fn place_parlay(
+        ctx: Context<PlaceParlay>,
+        parlay_key: u64,
+        pool_id: [u8; 32],
+        market_keys: Vec<u64>,
+        answer_keys: Vec<u64>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::place_parlay(ctx, parlay_key, pool_id, market_keys, answer_keys, amount)
+    }
+
+    pub fn settle_parlay(ctx: Context<SettleParlay>) -> Result<()> {
+        instructions::settle_parlay(ctx)
+    }
+
+    pub fn create_market_template(
+        ctx: Context<CreateMarketTemplate>,
+        template_key: u64,
+        params: MarketParams,
+        duration: u64,
+    ) -> Result<()> {
+        instructions::create_market_template(ctx, template_key, params, duration)
+    }
+
+    pub fn create_market_series(
+        ctx: Context<CreateMarketSeries>,
+        series_key: u64,
+        start_time: u64,
+        interval: u64,
+        max_epochs: u64,
+    ) -> Result<()> {
+        instructions::create_market_series(ctx, series_key, start_time, interval, max_epochs)
+    }
+
+    pub fn set_market_series_active(ctx: Context<SetMarketSeriesActive>, active: bool) -> Result<()> {
+        instructions::set_market_series_active(ctx, active)
+    }
+
+    pub fn crank_market_series(ctx: Context<CrankMarketSeries>, epoch: u64) -> Result<()> {
+        instructions::crank_market_series(ctx, epoch)
+    }
+
+    pub fn finish_series_market(ctx: Context<FinishSeriesMarket>) -> Result<()> {
+        instructions::finish_series_market(ctx)
+    }
+
+    pub fn seed_liquidity(ctx: Context<SeedLiquidity>, amount: u64) -> Result<()> {
+        instructions::seed_liquidity(ctx, amount)
+    }
+
+    pub fn refund_seed_liquidity(ctx: Context<RefundSeedLiquidity>) -> Result<()> {
+        instructions::refund_seed_liquidity(ctx)
+    }
 }
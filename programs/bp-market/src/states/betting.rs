@@ -11,4 +11,6 @@ pub struct BettingAccount {
     pub tokens: u64,
     pub create_time: u64,
     pub exist: bool,
+    pub fee_discount_percentage: u64, // Governance NFT tier applied to the latest bet
+    pub fee_discount_weight: u128,    // Sum of amount * discount over every bet on this account
 }
@@ -2,6 +2,16 @@ use anchor_lang::prelude::*;
 
 pub const CONFIG_SEED: &str = "config";
 pub const MAX_LOCKED_USERS: usize = 100;
+pub const MAX_FEE_DISCOUNT_TIERS: usize = 5;
+// min_parlay_outcome_stake was carved out of the reserved bytes
+pub const CONFIG_RESERVED_SPACE: usize = 64 - 8;
+
+/// Service-fee discount granted to bettors holding at least `min_nfts` governance NFTs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct FeeDiscountTier {
+    pub min_nfts: u8,
+    pub discount_percentage: u64, // basis points of the bettor's share of the service fee
+}
 
 #[account]
 #[derive(Debug, InitSpace)]
@@ -14,4 +24,14 @@ pub struct ConfigAccount {
     pub remain_account: Pubkey,
     #[max_len(MAX_LOCKED_USERS)]
     pub locked_users: Vec<Pubkey>,
+    pub referral_fee_percentage: u64, // Share of the service fee paid to referrers, in basis points
+    #[max_len(MAX_FEE_DISCOUNT_TIERS)]
+    pub fee_discount_tiers: Vec<FeeDiscountTier>,
+    pub paused: bool, // Blocks publishing, betting and claims on every market
+    pub resolution_deadline: u64, // Seconds after finish_time before anyone may force an adjournment
+    pub version: u8,
+    // Smallest stake that may open a new answer combination in a parlay pool, so the
+    // pool's MAX_PARLAY_OUTCOMES slots can't be filled with dust
+    pub min_parlay_outcome_stake: u64,
+    pub reserved: [u8; CONFIG_RESERVED_SPACE], // Room for new fields without a realloc
 }
\ No newline at end of file
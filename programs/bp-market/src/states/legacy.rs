@@ -0,0 +1,108 @@
+//! Layouts of accounts created before versioning was introduced (version 0).
+//! They are only read by the migrate_* instructions, which rewrite them in the current layout.
+
+use anchor_lang::prelude::*;
+
+use crate::constant::{DEFAULT_MIN_PARLAY_OUTCOME_STAKE, DEFAULT_RESOLUTION_DEADLINE};
+
+use super::{
+    ConfigAccount, MarketAccount, MarketStatus, CONFIG_RESERVED_SPACE, MARKET_RESERVED_SPACE,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfigAccountV0 {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub base_token: Pubkey,
+    pub cojam_fee_account: Pubkey,
+    pub charity_fee_account: Pubkey,
+    pub remain_account: Pubkey,
+    pub locked_users: Vec<Pubkey>,
+}
+
+impl From<ConfigAccountV0> for ConfigAccount {
+    fn from(legacy: ConfigAccountV0) -> Self {
+        Self {
+            bump: legacy.bump,
+            owner: legacy.owner,
+            base_token: legacy.base_token,
+            cojam_fee_account: legacy.cojam_fee_account,
+            charity_fee_account: legacy.charity_fee_account,
+            remain_account: legacy.remain_account,
+            locked_users: legacy.locked_users,
+            referral_fee_percentage: 0,
+            fee_discount_tiers: Vec::new(),
+            paused: false,
+            resolution_deadline: DEFAULT_RESOLUTION_DEADLINE,
+            version: 0,
+            min_parlay_outcome_stake: DEFAULT_MIN_PARLAY_OUTCOME_STAKE,
+            reserved: [0; CONFIG_RESERVED_SPACE],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MarketAccountV0 {
+    pub bump: u8,
+    pub creator: Pubkey,
+    pub market_key: u64,
+    pub betting_token: Pubkey,
+    pub title: String,
+    pub status: MarketStatus,
+    pub creator_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub charity_fee_percentage: u64,
+    pub approve_time: u64,
+    pub finish_time: u64,
+    pub adjourn_time: u64,
+    pub success_time: u64,
+    pub correct_answer_key: u64,
+    pub market_total_tokens: u64,
+    pub market_remain_tokens: u64,
+    pub market_reward_base_tokens: u64,
+}
+
+impl From<MarketAccountV0> for MarketAccount {
+    fn from(legacy: MarketAccountV0) -> Self {
+        Self {
+            bump: legacy.bump,
+            creator: legacy.creator,
+            market_key: legacy.market_key,
+            betting_token: legacy.betting_token,
+            title: legacy.title,
+            status: legacy.status,
+            creator_fee: legacy.creator_fee,
+            creator_fee_percentage: legacy.creator_fee_percentage,
+            service_fee_percentage: legacy.service_fee_percentage,
+            charity_fee_percentage: legacy.charity_fee_percentage,
+            approve_time: legacy.approve_time,
+            finish_time: legacy.finish_time,
+            adjourn_time: legacy.adjourn_time,
+            success_time: legacy.success_time,
+            correct_answer_key: legacy.correct_answer_key,
+            market_total_tokens: legacy.market_total_tokens,
+            market_remain_tokens: legacy.market_remain_tokens,
+            market_reward_base_tokens: legacy.market_reward_base_tokens,
+            referral_fee_percentage: 0,
+            market_referred_tokens: 0,
+            referral_fee: 0,
+            referral_remain_tokens: 0,
+            market_fee_discount_weight: 0,
+            fee_discount_tokens: 0,
+            fee_discount_remain_tokens: 0,
+            frozen: false,
+            void_time: 0,
+            void_reason: 0,
+            market_claimed_winning_tokens: 0,
+            market_claimed_referred_tokens: 0,
+            market_claimed_fee_discount_weight: 0,
+            version: 0,
+            series: Pubkey::default(),
+            series_epoch: 0,
+            previous_market_key: 0,
+            seed_liquidity_tokens: 0,
+            reserved: [0; MARKET_RESERVED_SPACE],
+        }
+    }
+}
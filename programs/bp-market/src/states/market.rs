@@ -9,6 +9,7 @@ pub enum MarketStatus {
     Finished,
     Success,
     Adjourn,
+    Voided,
 }
 
 #[account]
@@ -33,8 +34,29 @@ pub struct MarketAccount {
     pub market_total_tokens: u64,
     pub market_remain_tokens: u64,
     pub market_reward_base_tokens: u64,
+    pub referral_fee_percentage: u64,
+    pub market_referred_tokens: u64,
+    pub referral_fee: u64,
+    pub referral_remain_tokens: u64,
+    pub market_fee_discount_weight: u128,
+    pub fee_discount_tokens: u64,
+    pub fee_discount_remain_tokens: u64,
+    pub frozen: bool, // Blocks betting and claims on this market during incident response
+    pub void_time: u64,
+    pub void_reason: u8,
+    pub market_claimed_winning_tokens: u64, // Winning stake already paid out, the last winner takes the rounding dust
+    pub market_claimed_referred_tokens: u64,
+    pub market_claimed_fee_discount_weight: u128,
+    pub version: u8,
+    pub series: Pubkey, // MarketSeries that created this market, default for published markets
+    pub series_epoch: u64,
+    pub previous_market_key: u64, // Previous market of the same series, 0 for the first one
+    pub seed_liquidity_tokens: u64, // Creator subsidy in the vault, paid to winners on success or refunded on adjourn
+    pub reserved: [u8; MARKET_RESERVED_SPACE], // Room for new fields without a realloc
 }
 
 pub const MAX_MARKET_KEY: usize = 100;
 
 pub const MAX_TITLE_LEN: usize = 100;
+
+pub const MARKET_RESERVED_SPACE: usize = 8;
@@ -2,8 +2,16 @@ pub mod market;
 pub mod betting;
 pub mod answer;
 pub mod config;
+pub mod referral;
+pub mod legacy;
+pub mod parlay;
+pub mod series;
 
 pub use market::*;
 pub use betting::*;
 pub use answer::*;
 pub use config::*;
+pub use referral::*;
+pub use legacy::*;
+pub use parlay::*;
+pub use series::*;
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+pub const PARLAY_SEED: &str = "parlay";
+pub const PARLAY_POOL_SEED: &str = "parlay_pool";
+
+pub const MAX_PARLAY_LEGS: usize = 4; // The packed market keys fill a 32-byte pool seed
+pub const MIN_PARLAY_LEGS: usize = 2;
+pub const MAX_PARLAY_OUTCOMES: usize = 32;
+
+/// How a leg's market resolved, recorded on the pool when it is settled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ParlayLegResult {
+    Pending,
+    Correct { answer_key: u64 },
+    Void, // Market was adjourned or voided, the leg is ignored when matching outcomes
+}
+
+/// What a parlay paid out when it was settled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ParlaySettlement {
+    Won,
+    Refunded, // No combination in the pool won, every stake is returned
+    Forfeited,
+}
+
+/// Total stake placed on one combination of answers across the pool's markets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct ParlayOutcome {
+    #[max_len(MAX_PARLAY_LEGS)]
+    pub answer_keys: Vec<u64>,
+    pub stake: u64,
+}
+
+/// One pool per combination of markets. Every parlay on those markets stakes into it,
+/// and the parlays whose answers match the results split it pro-rata.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ParlayPoolAccount {
+    pub bump: u8,
+    pub pool_id: [u8; 32], // parlay_pool_id(market_keys)
+    pub mint: Pubkey,
+    #[max_len(MAX_PARLAY_LEGS)]
+    pub market_keys: Vec<u64>, // Strictly ascending
+    #[max_len(MAX_PARLAY_OUTCOMES)]
+    pub outcomes: Vec<ParlayOutcome>,
+    pub total_stake: u64,
+    pub remain_tokens: u64,
+    pub settled: bool,
+    #[max_len(MAX_PARLAY_LEGS)]
+    pub leg_results: Vec<ParlayLegResult>,
+    pub winning_stake: u64,
+    pub claimed_winning_stake: u64,
+    pub create_time: u64,
+    pub settle_time: u64,
+    pub exist: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct ParlayLeg {
+    pub market: Pubkey,
+    pub market_key: u64,
+    pub answer_key: u64,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ParlayAccount {
+    pub bump: u8,
+    pub voter: Pubkey,
+    pub parlay_key: u64,
+    pub pool: Pubkey,
+    #[max_len(MAX_PARLAY_LEGS)]
+    pub legs: Vec<ParlayLeg>, // Same order as the pool's market_keys
+    pub stake: u64,
+    pub create_time: u64,
+    pub exist: bool,
+}
+
+impl ParlayPoolAccount {
+    /// Whether `answer_keys` wins once every leg is resolved. Void legs match any answer.
+    pub fn is_winning(&self, answer_keys: &[u64]) -> bool {
+        self.leg_results
+            .iter()
+            .zip(answer_keys)
+            .all(|(result, answer_key)| match result {
+                ParlayLegResult::Correct { answer_key: correct } => correct == answer_key,
+                ParlayLegResult::Void => true,
+                ParlayLegResult::Pending => false,
+            })
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+pub const REFERRAL_SEED: &str = "referral";
+pub const REFERRAL_MARKET_SEED: &str = "referral_market";
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ReferralAccount {
+    pub bump: u8,
+    pub referrer: Pubkey,
+    pub mint: Pubkey,           // Betting token the referred volume is denominated in
+    pub referred_tokens: u64,
+    pub referred_bets: u64,
+    pub claimed_tokens: u64,
+    pub exist: bool,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ReferralMarketAccount {
+    pub bump: u8,
+    pub referrer: Pubkey,
+    pub market_key: u64,
+    pub referred_tokens: u64,
+    pub claimed: bool,
+    pub exist: bool,
+}
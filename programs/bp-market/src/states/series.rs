@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use super::{MAX_ANWSER, MAX_TITLE_LEN};
+
+pub const MARKET_TEMPLATE_SEED: &str = "market_template";
+pub const MARKET_SERIES_SEED: &str = "market_series";
+
+/// Placeholder in a template's title pattern replaced by the market's epoch
+pub const SERIES_EPOCH_PLACEHOLDER: &str = "{epoch}";
+
+/// Series keys must fit in 31 bits so series market keys can be derived without collisions
+pub const MAX_SERIES_KEY: u64 = (1 << 31) - 1;
+pub const MAX_SERIES_EPOCH: u64 = u32::MAX as u64;
+
+/// Everything publish_market needs, so a series can open the same market every epoch
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct MarketTemplate {
+    pub bump: u8,
+    pub template_key: u64,
+    pub creator: Pubkey,
+    #[max_len(MAX_TITLE_LEN)]
+    pub title_pattern: String, // "{epoch}" is replaced by the series epoch
+    pub betting_token: Pubkey,
+    pub create_fee: u64,
+    pub creator_fee_percentage: u64,
+    pub service_fee_percentage: u64,
+    pub charity_fee_percentage: u64,
+    #[max_len(MAX_ANWSER)]
+    pub answer_keys: Vec<u64>,
+    pub duration: u64, // Seconds a series market stays open before anyone may finish it
+    pub exist: bool,
+}
+
+/// Opens one market from `template` every `interval` seconds starting at `start_time`.
+/// Epoch `n` starts at `start_time + n * interval` and its market key is
+/// `series_market_key(series_key, n)`.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct MarketSeries {
+    pub bump: u8,
+    pub series_key: u64,
+    pub template_key: u64,
+    pub start_time: u64,
+    pub interval: u64,
+    pub max_epochs: u64, // 0 for an open-ended series
+    pub next_epoch: u64, // Lowest epoch the crank may still open
+    pub last_market_key: u64,
+    pub market_count: u64,
+    pub active: bool,
+    pub exist: bool,
+}
@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
+use crate::constant::{APPROVE_MARKET_VALIDITY_DATE, BASIS_POINTS};
 use crate::error::ProgramErrorCode;
+use crate::states::{
+    FeeDiscountTier, MarketAccount, MarketStatus, MAX_SERIES_EPOCH, MAX_SERIES_KEY, SERIES_EPOCH_PLACEHOLDER,
+};
 
 pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
     let result = (amount as u128)
@@ -8,4 +12,299 @@ pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
         .ok_or(ProgramErrorCode::Overflow)?;
 
     Ok(result as u64)
-}
\ No newline at end of file
+}
+
+/// Returns the discount of the highest tier the NFT count qualifies for.
+/// Tiers are stored sorted by ascending `min_nfts`.
+pub fn fee_discount_for(tiers: &[FeeDiscountTier], nft_count: u8) -> u64 {
+    tiers
+        .iter()
+        .rev()
+        .find(|tier| nft_count >= tier.min_nfts)
+        .map(|tier| tier.discount_percentage)
+        .unwrap_or(0)
+}
+
+/// Splits `pool` pro-rata by `share / total_shares`, flooring every claim except the
+/// one that completes `total_shares`, which receives whatever is left in the pool.
+/// This way the claims always add up to exactly `pool` and no rounding dust is left behind.
+pub fn pro_rata_share(
+    pool: u64,
+    pool_remaining: u64,
+    share: u128,
+    claimed_shares: u128,
+    total_shares: u128,
+) -> Option<u64> {
+    if share == 0 || total_shares == 0 {
+        return Some(0);
+    }
+
+    if claimed_shares.checked_add(share)? >= total_shares {
+        return Some(pool_remaining);
+    }
+
+    let amount = (pool as u128)
+        .checked_mul(share)?
+        .checked_div(total_shares)?;
+
+    Some((amount as u64).min(pool_remaining))
+}
+
+/// Fees taken out of a market when it resolves successfully
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketFees {
+    pub creator_fee: u64,
+    pub service_fee: u64,
+    pub charity_fee: u64,
+    pub referral_fee: u64,
+    pub fee_discount: u64,
+}
+
+/// Splits the market pool into fees and the winners' reward base, updating the market in place.
+/// Shared with off-chain clients so they can preview a resolution.
+pub fn calculate_market_fees(market_account: &mut MarketAccount) -> Result<MarketFees> {
+    let remain_tokens = market_account.market_remain_tokens as u128;
+
+    let creator_fee_percentage = market_account.creator_fee_percentage as u128;
+    let service_fee_percentage = market_account.service_fee_percentage as u128;
+    let charity_fee_percentage = market_account.charity_fee_percentage as u128;
+
+    let old_creator_fee = market_account.creator_fee as u128;
+
+    let additional_creator_fee = remain_tokens
+        .checked_mul(creator_fee_percentage)
+        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let total_creator_fee = old_creator_fee
+        .checked_add(additional_creator_fee)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let service_fee = remain_tokens
+        .checked_mul(service_fee_percentage)
+        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let charity_fee = remain_tokens
+        .checked_mul(charity_fee_percentage)
+        .and_then(|result| result.checked_div(BASIS_POINTS as u128))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    // Referrers earn a share of the service fee proportional to the volume they brought in.
+    // It stays in the vault until each referrer claims it with claim_referral_reward.
+    let referral_fee = if market_account.market_total_tokens == 0 {
+        0
+    } else {
+        service_fee
+            .checked_mul(market_account.referral_fee_percentage as u128)
+            .and_then(|result| result.checked_mul(market_account.market_referred_tokens as u128))
+            .and_then(|result| {
+                result.checked_div(
+                    (BASIS_POINTS as u128) * (market_account.market_total_tokens as u128),
+                )
+            })
+            .ok_or(ProgramErrorCode::Overflow)?
+    };
+
+    let service_fee = service_fee
+        .checked_sub(referral_fee)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    // Governance NFT holders get part of their share of the service fee back.
+    // The rebate stays in the vault and is paid out with each bettor's receive_token.
+    let fee_discount = if market_account.market_total_tokens == 0 {
+        0
+    } else {
+        service_fee
+            .checked_mul(market_account.market_fee_discount_weight)
+            .and_then(|result| {
+                result.checked_div(
+                    (BASIS_POINTS as u128) * (market_account.market_total_tokens as u128),
+                )
+            })
+            .ok_or(ProgramErrorCode::Overflow)?
+    };
+
+    let service_fee = service_fee
+        .checked_sub(fee_discount)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    let remaining_tokens = remain_tokens
+        .checked_sub(additional_creator_fee)
+        .and_then(|result| result.checked_sub(service_fee))
+        .and_then(|result| result.checked_sub(referral_fee))
+        .and_then(|result| result.checked_sub(fee_discount))
+        .and_then(|result| result.checked_sub(charity_fee))
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    // The creator's seed liquidity is fee-free and goes to the winners on top of the losing stakes
+    let remaining_tokens = remaining_tokens
+        .checked_add(market_account.seed_liquidity_tokens as u128)
+        .ok_or(ProgramErrorCode::Overflow)?;
+
+    // Update market_reward_base_tokens
+    market_account.market_reward_base_tokens = remaining_tokens as u64;
+    
+    // Update creator_fee to include the additional fee
+    market_account.creator_fee = total_creator_fee as u64;
+
+    // Update market_remain_tokens (subtract the fees that were taken out, add the seed liquidity)
+    market_account.market_remain_tokens = remaining_tokens as u64;
+
+    market_account.referral_fee = referral_fee as u64;
+    market_account.referral_remain_tokens = referral_fee as u64;
+
+    market_account.fee_discount_tokens = fee_discount as u64;
+    market_account.fee_discount_remain_tokens = fee_discount as u64;
+
+    Ok(MarketFees {
+        creator_fee: total_creator_fee as u64,
+        service_fee: service_fee as u64,
+        charity_fee: charity_fee as u64,
+        referral_fee: referral_fee as u64,
+        fee_discount: fee_discount as u64,
+    })
+}
+
+/// Time after which anyone may force-adjourn a market: `resolution_deadline` after the
+/// market was finished, or after its betting window closes for a market the owner never
+/// finished. Approved markets count as closed `APPROVE_MARKET_VALIDITY_DATE` after approval.
+pub fn force_adjourn_deadline(
+    status: MarketStatus,
+    approve_time: u64,
+    finish_time: u64,
+    resolution_deadline: u64,
+) -> Result<u64> {
+    let closed_at = match status {
+        MarketStatus::Finished => finish_time,
+        MarketStatus::Approve => approve_time
+            .checked_add(APPROVE_MARKET_VALIDITY_DATE)
+            .ok_or(ProgramErrorCode::Overflow)?,
+        _ => return err!(ProgramErrorCode::MarketNotFinished),
+    };
+    Ok(closed_at
+        .checked_add(resolution_deadline)
+        .ok_or(ProgramErrorCode::Overflow)?)
+}
+
+/// Seed identifying the parlay pool shared by every parlay on the same set of markets:
+/// the market keys packed little-endian and zero-padded to 32 bytes. `market_keys` must be
+/// strictly ascending, so each combination maps to a single pool.
+pub fn parlay_pool_id(market_keys: &[u64]) -> [u8; 32] {
+    let mut pool_id = [0u8; 32];
+    for (chunk, market_key) in pool_id.chunks_exact_mut(8).zip(market_keys) {
+        chunk.copy_from_slice(&market_key.to_le_bytes());
+    }
+    pool_id
+}
+
+/// Market key of a series epoch: the top bit marks series markets, followed by the
+/// 31-bit series key and the 32-bit epoch. Callers bound both with `MAX_SERIES_KEY`
+/// and `MAX_SERIES_EPOCH`.
+pub fn series_market_key(series_key: u64, epoch: u64) -> u64 {
+    (1 << 63) | ((series_key & MAX_SERIES_KEY) << 32) | (epoch & MAX_SERIES_EPOCH)
+}
+
+/// Title of a series market, with the template's epoch placeholder filled in.
+pub fn series_market_title(title_pattern: &str, epoch: u64) -> String {
+    title_pattern.replace(SERIES_EPOCH_PLACEHOLDER, &epoch.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiers() -> Vec<FeeDiscountTier> {
+        vec![
+            FeeDiscountTier { min_nfts: 1, discount_percentage: 1_000 },
+            FeeDiscountTier { min_nfts: 3, discount_percentage: 2_500 },
+            FeeDiscountTier { min_nfts: 5, discount_percentage: 5_000 },
+        ]
+    }
+
+    #[test]
+    fn test_fee_discount_no_nfts() {
+        assert_eq!(fee_discount_for(&tiers(), 0), 0);
+        assert_eq!(fee_discount_for(&[], 10), 0);
+    }
+
+    #[test]
+    fn test_fee_discount_picks_highest_tier() {
+        assert_eq!(fee_discount_for(&tiers(), 1), 1_000);
+        assert_eq!(fee_discount_for(&tiers(), 4), 2_500);
+        assert_eq!(fee_discount_for(&tiers(), 5), 5_000);
+        assert_eq!(fee_discount_for(&tiers(), 50), 5_000);
+    }
+
+    #[test]
+    fn test_pro_rata_share_last_claimer_takes_dust() {
+        let pool = 100;
+        let mut remaining = pool;
+        let mut paid = Vec::new();
+        for (claimed, _) in (0..3).enumerate() {
+            let amount = pro_rata_share(pool, remaining, 1, claimed as u128, 3).unwrap();
+            remaining -= amount;
+            paid.push(amount);
+        }
+        assert_eq!(paid, vec![33, 33, 34]);
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn test_pro_rata_share_zero_shares() {
+        assert_eq!(pro_rata_share(100, 100, 0, 0, 10), Some(0));
+        assert_eq!(pro_rata_share(100, 100, 5, 0, 0), Some(0));
+    }
+
+    #[test]
+    fn test_parlay_pool_id_is_unique_per_market_set() {
+        let pool_id = parlay_pool_id(&[1, 2]);
+        assert_eq!(&pool_id[..8], &1u64.to_le_bytes());
+        assert_eq!(&pool_id[8..16], &2u64.to_le_bytes());
+        assert!(pool_id[16..].iter().all(|byte| *byte == 0));
+
+        assert_ne!(parlay_pool_id(&[1, 2]), parlay_pool_id(&[1, 2, 3]));
+        assert_ne!(parlay_pool_id(&[0, 2]), parlay_pool_id(&[2]));
+    }
+
+    #[test]
+    fn test_series_market_key_is_unique_per_series_and_epoch() {
+        assert_eq!(series_market_key(0, 0), 1 << 63);
+        assert_eq!(series_market_key(1, 2), (1 << 63) | (1 << 32) | 2);
+        assert_ne!(series_market_key(1, 0), series_market_key(0, 1 << 31));
+        assert_ne!(
+            series_market_key(MAX_SERIES_KEY, MAX_SERIES_EPOCH),
+            series_market_key(MAX_SERIES_KEY - 1, MAX_SERIES_EPOCH)
+        );
+    }
+
+    #[test]
+    fn test_series_market_title() {
+        assert_eq!(series_market_title("Top song of week {epoch}?", 12), "Top song of week 12?");
+        assert_eq!(series_market_title("Top song this week?", 12), "Top song this week?");
+    }
+
+    #[test]
+    fn test_force_adjourn_deadline_finished_market() {
+        assert_eq!(
+            force_adjourn_deadline(MarketStatus::Finished, 1_000, 5_000, 100).unwrap(),
+            5_100
+        );
+    }
+
+    #[test]
+    fn test_force_adjourn_deadline_unfinished_market() {
+        assert_eq!(
+            force_adjourn_deadline(MarketStatus::Approve, 1_000, 0, 100).unwrap(),
+            1_000 + APPROVE_MARKET_VALIDITY_DATE + 100
+        );
+    }
+
+    #[test]
+    fn test_force_adjourn_deadline_rejects_resolved_markets() {
+        for status in [MarketStatus::Draft, MarketStatus::Success, MarketStatus::Adjourn, MarketStatus::Voided] {
+            assert!(force_adjourn_deadline(status, 1_000, 5_000, 100).is_err());
+        }
+        assert!(force_adjourn_deadline(MarketStatus::Finished, 0, u64::MAX, 1).is_err());
+    }
+}
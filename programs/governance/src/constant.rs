@@ -7,6 +7,23 @@ pub const CONSTANT_REWARD_TOKEN: u64 = 5;
 pub const DURATION_HOURS: u64 = 24;
 pub const MAX_VOTABLE_NFT: u8 = 5;
 pub const MIN_REQUIRED_NFT: u8 = 3;
+pub const MAX_DELEGATORS: usize = 32;
+pub const MAX_SUPPLY_CHECKPOINTS: usize = 128;
+pub const MAX_VOTER_CHECKPOINTS: usize = 50;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const WEIGHT_PRECISION: u64 = 1_000;
+
+// Account layout versions
+pub const GOVERNANCE_CONFIG_VERSION: u8 = 1;
+pub const GOVERNANCE_ITEM_VERSION: u8 = 1;
+pub const RESERVED_SPACE: usize = 64;
+// quorum_bps, approval_bps, answer_tie_break, vote_weighting, quest_reward_token and
+// decision_reward_token and min_reward_pool were carved out of the config's reserved bytes
+pub const GOVERNANCE_CONFIG_RESERVED_SPACE: usize = RESERVED_SPACE - 30;
+// reveal_duration, reward_pool_amount, voting_supply, answer_voter_count, the three
+// nft turnouts, reward_pool_claimed_votes and pays_reward_pool were carved out of the
+// item's reserved bytes, two blocks since the first was used up
+pub const GOVERNANCE_ITEM_RESERVED_SPACE: usize = 2 * RESERVED_SPACE - 65;
 
 // PDA seed constants
 pub const GOVERNANCE_SEED: &[u8] = b"governance";
@@ -19,4 +36,11 @@ pub const DECISION_VOTE_SEED: &[u8] = b"decision_vote";
 pub const ANSWER_VOTE_SEED: &[u8] = b"answer_vote";
 pub const VOTER_RECORD_SEED: &[u8] = b"voter_record";
 pub const DECISION_VOTER_SEED: &[u8] = b"decision_voter";
+pub const VOTER_STAKE_SEED: &[u8] = b"voter_stake";
+pub const DELEGATE_INDEX_SEED: &[u8] = b"delegate_index";
+pub const PROPOSAL_VOTER_SEED: &[u8] = b"proposal_voter";
+pub const SUPPLY_CHECKPOINTS_SEED: &[u8] = b"supply_checkpoints";
+pub const ANSWER_COMMIT_SEED: &[u8] = b"answer_commit";
+pub const REWARD_POOL_SEED: &[u8] = b"reward_pool";
+pub const PHASE_REWARD_CLAIM_SEED: &[u8] = b"phase_reward_claim";
 pub const TREASURY_TOKEN_ACCOUNT_SEED: &[u8] = b"token_account";
\ No newline at end of file
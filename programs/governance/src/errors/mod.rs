@@ -145,4 +145,94 @@ pub enum GovernanceError {
 
     #[msg("Must participate in quest voting to vote on decision")]
     NoQuestParticipation,
+
+    #[msg("Account is already on the latest layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Account data does not match the expected layout")]
+    InvalidAccountData,
+
+    #[msg("NFT is not a verified member of the governance collection")]
+    NftNotInCollection,
+
+    #[msg("No governance NFTs are staked")]
+    NoStakedNfts,
+
+    #[msg("NFTs cannot be unstaked while the voter has an active vote")]
+    StakeLocked,
+
+    #[msg("Staked NFTs are delegated; undelegate first")]
+    VotesDelegated,
+
+    #[msg("Staked NFTs are not delegated")]
+    VotesNotDelegated,
+
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+
+    #[msg("Delegate has reached the maximum number of delegators")]
+    TooManyDelegators,
+
+    #[msg("Weighted turnout is below the quorum")]
+    QuorumNotReached,
+
+    #[msg("Basis points must not exceed 10000")]
+    InvalidBps,
+
+    #[msg("Tied votes can only be resolved by the authority")]
+    TiedVoteNeedsAuthority,
+
+    #[msg("Answer options have not been set for this item")]
+    AnswerOptionsNotSet,
+
+    #[msg("Governance item has no transition due")]
+    NothingToAdvance,
+
+    #[msg("Answer option accounts do not match the item's answer keys")]
+    AnswerOptionMismatch,
+
+    #[msg("Tie-break answer must be one of the tied options")]
+    InvalidTieBreakAnswer,
+
+    #[msg("Voter has no counted vote to change")]
+    NotVoted,
+
+    #[msg("New vote choice is the same as the current one")]
+    SameVoteChoice,
+
+    #[msg("Answer votes for this item must be committed and revealed")]
+    CommitRevealRequired,
+
+    #[msg("Answer votes for this item are not in commit-reveal mode")]
+    CommitRevealNotEnabled,
+
+    #[msg("Reveal window is not open")]
+    RevealWindowClosed,
+
+    #[msg("Revealed answer and salt do not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Answer vote already revealed")]
+    AlreadyRevealed,
+
+    #[msg("Reward pool can no longer be funded")]
+    RewardPoolClosed,
+
+    #[msg("Reward pool can only be refunded once the item is cancelled")]
+    RewardPoolNotRefundable,
+
+    #[msg("Reward pool is empty")]
+    RewardPoolEmpty,
+
+    #[msg("Quest pays rewards from its pool; pass the pool and winning answer option accounts")]
+    RewardPoolAccountsRequired,
+
+    #[msg("No governance rewards to claim for this quest")]
+    NothingToClaim,
+
+    #[msg("Supply history no longer reaches back to the item's start slot")]
+    SupplyHistoryUnavailable,
+
+    #[msg("Voter records passed do not add up to the proposal's total vote")]
+    ProposalVotesMismatch,
 }
\ No newline at end of file
@@ -33,7 +33,7 @@ pub struct CancelAnswer<'info> {
 
 pub fn cancel_answer(
     ctx: Context<CancelAnswer>,
-    _quest_key: u64,
+    quest_key: u64,
     reason: String
 ) -> Result<()> {
     let answer_vote = &mut ctx.accounts.answer_vote;
@@ -48,8 +48,25 @@ pub fn cancel_answer(
     answer_vote.finalized = true;
     answer_vote.winning_answer = 0;
 
-    // Log the cancellation reason
-    msg!("Answer voting cancelled. Reason: {}", reason);
+    emit!(AnswerCancelled {
+        quest_key,
+        reason: CancelReason::Authority,
+        note: reason,
+        total_voted: answer_vote.total_voted,
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
+}
+
+#[event]
+pub struct AnswerCancelled {
+    pub quest_key: u64,
+    pub reason: CancelReason,
+    /// The authority's explanation, empty when finalization cancelled the vote
+    pub note: String,
+    pub total_voted: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
 }
\ No newline at end of file
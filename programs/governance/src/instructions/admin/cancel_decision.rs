@@ -66,7 +66,10 @@ pub fn cancel_decision(ctx: Context<CancelDecision>, quest_key: u64) -> Result<(
 
     emit!(DecisionCancelled {
         quest_key,
+        result: governance_item.decision_result.clone(),
         cancelled_by: ctx.accounts.authority.key(),
+        count_success: decision_vote.count_success,
+        count_adjourn: decision_vote.count_adjourn,
         total_votes_at_cancellation: decision_vote.total_voted,
         timestamp: Clock::get()?.unix_timestamp,
     });
@@ -83,7 +86,10 @@ pub fn cancel_decision(ctx: Context<CancelDecision>, quest_key: u64) -> Result<(
 #[event]
 pub struct DecisionCancelled {
     pub quest_key: u64,
+    pub result: DecisionResult,
     pub cancelled_by: Pubkey,
+    pub count_success: u64,
+    pub count_adjourn: u64,
     pub total_votes_at_cancellation: u64,
     pub timestamp: i64,
 }
\ No newline at end of file
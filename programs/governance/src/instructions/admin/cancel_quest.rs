@@ -55,7 +55,12 @@ pub fn cancel_quest(ctx: Context<CancelQuest>, quest_key: u64) -> Result<()> {
     
     emit!(QuestCancelled {
         quest_key,
+        result: governance_item.quest_result.clone(),
+        count_approver: quest_vote.count_approver,
+        count_rejector: quest_vote.count_rejector,
         total_voted: quest_vote.total_voted,
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
     });
     
     Ok(())
@@ -64,5 +69,10 @@ pub fn cancel_quest(ctx: Context<CancelQuest>, quest_key: u64) -> Result<()> {
 #[event]
 pub struct QuestCancelled {
     pub quest_key: u64,
+    pub result: QuestResult,
+    pub count_approver: u64,
+    pub count_rejector: u64,
     pub total_voted: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
 }
\ No newline at end of file
@@ -2,11 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::states::*;
 use crate::errors::GovernanceError;
+use crate::utils::pay_answer_reward;
 use crate::constant::{
     ANSWER_VOTE_SEED,
     GOVERNANCE_CONFIG_SEED,
     GOVERNANCE_ITEM_SEED,
     GOVERNANCE_SEED,
+    REWARD_POOL_SEED,
     TREASURY_SEED,
     TREASURY_TOKEN_ACCOUNT_SEED,
 };
@@ -28,6 +30,7 @@ pub struct DistributeReward<'info> {
     pub governance: Account<'info, Governance>,
 
     #[account(
+        mut,
         seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
         bump = governance_item.bump
     )]
@@ -81,13 +84,32 @@ pub struct DistributeReward<'info> {
     )]
     pub treasury_token_account: Account<'info, TokenAccount>,
 
+    /// Quest's reward pool, required when its answer rewards are paid from one
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, quest_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Tally of the winning answer, required with the reward pool
+    #[account(
+        seeds = [
+            b"answer_option",
+            quest_key.to_le_bytes().as_ref(),
+            governance_item.answer_result.to_le_bytes().as_ref()
+        ],
+        bump = winning_answer_option.bump
+    )]
+    pub winning_answer_option: Option<Account<'info, AnswerOption>>,
+
     pub token_program: Program<'info, Token>,
 }
 
 pub fn distribute_reward(ctx: Context<DistributeReward>, quest_key: u64) -> Result<()> {
     let config = &ctx.accounts.config;
     let governance = &mut ctx.accounts.governance;
-    let governance_item = &ctx.accounts.governance_item;
+    let governance_item = &mut ctx.accounts.governance_item;
     let answer_vote = &ctx.accounts.answer_vote;
     let voter_record = &mut ctx.accounts.voter_record;
 
@@ -117,31 +139,41 @@ pub fn distribute_reward(ctx: Context<DistributeReward>, quest_key: u64) -> Resu
         GovernanceError::VoterAlreadyRewarded
     );
 
-    let total_reward = voter_votes
-        .checked_mul(config.constant_reward_token)
-        .ok_or(GovernanceError::MathOverflow)?;
+    let treasury_bump = config.treasury_bump;
+    let signer_seeds: [&[u8]; 2] = [TREASURY_SEED, &[treasury_bump]];
+    let signer_seeds_refs: [&[&[u8]]; 1] = [&signer_seeds];
 
-    require!(
-        ctx.accounts.treasury_token_account.amount >= total_reward,
-        GovernanceError::InsufficientTreasuryBalance
-    );
+    // Quests funded past min_reward_pool split their pool pro-rata among the winning
+    // answer's votes; the rest pay the constant per-vote reward from the treasury
+    let (total_reward, from_pool) = pay_answer_reward(
+        config,
+        governance_item,
+        voter_record,
+        ctx.accounts.reward_pool_token_account.as_deref(),
+        ctx.accounts.winning_answer_option.as_deref(),
+    )?;
+    let source = match ctx.accounts.reward_pool_token_account.as_ref() {
+        Some(reward_pool_token_account) if from_pool => reward_pool_token_account.to_account_info(),
+        _ => {
+            require!(
+                ctx.accounts.treasury_token_account.amount >= total_reward,
+                GovernanceError::InsufficientTreasuryBalance
+            );
+            ctx.accounts.treasury_token_account.to_account_info()
+        }
+    };
 
     let cpi_accounts = Transfer {
-        from: ctx.accounts.treasury_token_account.to_account_info(),
+        from: source,
         to: ctx.accounts.voter_token_account.to_account_info(),
         authority: ctx.accounts.treasury_pda.to_account_info(),
     };
 
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let treasury_bump = config.treasury_bump;
-    let signer_seeds: [&[u8]; 2] = [TREASURY_SEED, &[treasury_bump]];
-    let signer_seeds_refs: [&[&[u8]]; 1] = [&signer_seeds];
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer_seeds_refs);
 
     token::transfer(cpi_ctx, total_reward)?;
 
-    voter_record.rewarded = true;
-
     governance.total_rewards_distributed = governance
         .total_rewards_distributed
         .checked_add(total_reward)
@@ -153,6 +185,8 @@ pub fn distribute_reward(ctx: Context<DistributeReward>, quest_key: u64) -> Resu
         answer_key: voter_record.answer_key,
         vote_count: voter_votes,
         reward_amount: total_reward,
+        total_rewards_distributed: governance.total_rewards_distributed,
+        timestamp: Clock::get()?.unix_timestamp,
     });
 
     Ok(())
@@ -165,4 +199,6 @@ pub struct RewardDistributed {
     pub answer_key: u64,
     pub vote_count: u64,
     pub reward_amount: u64,
+    pub total_rewards_distributed: u64,
+    pub timestamp: i64,
 }
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, ANSWER_VOTE_SEED};
+use crate::utils::quorum_reached;
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, ANSWER_VOTE_SEED, SUPPLY_CHECKPOINTS_SEED};
+use crate::instructions::admin::cancel_answer::AnswerCancelled;
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -16,6 +18,13 @@ pub struct FinalizeAnswer<'info> {
     )]
     pub config: Account<'info, GovernanceConfig>,
 
+    /// Voting supply history, read at the item's start_slot for the quorum
+    #[account(
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
     #[account(
         mut,
         seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
@@ -33,37 +42,159 @@ pub struct FinalizeAnswer<'info> {
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Finalizes the answer phase from the `AnswerOption` tallies.
+///
+/// `remaining_accounts` must hold the answer option PDA of every key in
+/// `governance_item.answer_keys`, in the same order; options nobody voted for were
+/// never created and count as 0. Ties are resolved by `config.answer_tie_break`,
+/// `tie_break_answer` being the authority's pick under `AnswerTieBreak::Authority`.
+/// Without quorum, or without any votes, the answer vote is cancelled instead.
+/// advance_governance_item performs the same finalization for every case but an authority tie-break.
 pub fn finalize_answer(
     ctx: Context<FinalizeAnswer>,
     quest_key: u64,
+    tie_break_answer: Option<u64>,
 ) -> Result<()> {
-    let governance_item = &ctx.accounts.governance_item;
-    let answer_vote = &mut ctx.accounts.answer_vote;
-    let clock = &ctx.accounts.clock;
-
     msg!(
         "[ONCHAIN] [finalize_answer] Finalizing quest {}",
         quest_key
     );
 
+    finalize_answer_vote(
+        &ctx.accounts.config,
+        &mut ctx.accounts.governance_item,
+        &mut ctx.accounts.answer_vote,
+        &ctx.accounts.supply_checkpoints,
+        ctx.remaining_accounts,
+        tie_break_answer,
+        ctx.accounts.authority.key(),
+    )
+}
+
+/// Tallies `answer_options` and records the winner, or cancels the answer vote.
+/// A tie under `AnswerTieBreak::Authority` fails without `tie_break_answer`.
+pub fn finalize_answer_vote(
+    config: &GovernanceConfig,
+    governance_item: &mut GovernanceItem,
+    answer_vote: &mut AnswerVote,
+    supply_checkpoints: &SupplyCheckpoints,
+    answer_options: &[AccountInfo],
+    tie_break_answer: Option<u64>,
+    finalized_by: Pubkey,
+) -> Result<()> {
+    let quest_key = governance_item.quest_key;
+    let clock = Clock::get()?;
+
     require!(
         !answer_vote.finalized,
         GovernanceError::AnswerVoteFinalized
     );
 
     require!(
-        governance_item.answer_result != 0,
-        GovernanceError::AnswerResultEmpty
+        governance_item.answer_start_time != 0,
+        GovernanceError::AnswerVotingNotStarted
+    );
+
+    // In commit-reveal mode this waits for the reveal window too
+    require!(
+        clock.unix_timestamp > governance_item.answer_close_time(),
+        GovernanceError::VotingPeriodNotEnded
     );
 
-    answer_vote.winning_answer = governance_item.answer_result;
+    require!(
+        answer_options.len() == governance_item.answer_keys.len(),
+        GovernanceError::AnswerOptionMismatch
+    );
+
+    let mut tallies = Vec::with_capacity(governance_item.answer_keys.len());
+    for (&answer_key, option_info) in governance_item.answer_keys.iter().zip(answer_options) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"answer_option", quest_key.to_le_bytes().as_ref(), answer_key.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(option_info.key(), expected, GovernanceError::AnswerOptionMismatch);
+
+        let votes = if option_info.owner == &crate::ID {
+            let option = AnswerOption::try_deserialize(&mut &option_info.try_borrow_data()?[..])?;
+            option.total_votes
+        } else {
+            0
+        };
+        tallies.push((answer_key, votes));
+    }
+
+    // Every counted vote must be accounted for by the options passed in
+    let counted = tallies
+        .iter()
+        .try_fold(0u64, |sum, &(_, votes)| sum.checked_add(votes))
+        .ok_or(GovernanceError::MathOverflow)?;
+    require!(
+        counted == answer_vote.total_voted,
+        GovernanceError::AnswerOptionMismatch
+    );
+
+    let voting_supply = governance_item.voting_supply(supply_checkpoints)?;
+    let quorum_met = governance_item.answer_voter_count >= config.min_total_vote
+        && quorum_reached(
+            config.quorum_bps,
+            governance_item.answer_nft_turnout,
+            answer_vote.total_voted,
+            voting_supply,
+        );
+
+    let outcome = if !quorum_met {
+        Err(CancelReason::QuorumNotReached)
+    } else {
+        match tally_answers(&tallies) {
+            AnswerTally::Winner(answer_key) => Ok(answer_key),
+            AnswerTally::NoVotes => Err(CancelReason::NoVotes),
+            AnswerTally::Tied(tied) => match config.answer_tie_break {
+                AnswerTieBreak::FirstListed => Ok(tied[0]),
+                AnswerTieBreak::Cancel => Err(CancelReason::TiedVote),
+                AnswerTieBreak::Authority => {
+                    let answer_key = tie_break_answer.ok_or(GovernanceError::TiedVoteNeedsAuthority)?;
+                    require!(
+                        tied.contains(&answer_key),
+                        GovernanceError::InvalidTieBreakAnswer
+                    );
+                    Ok(answer_key)
+                }
+            },
+        }
+    };
+
     answer_vote.finalized = true;
 
-    emit!(AnswerFinalized {
-        quest_key,
-        winning_answer: answer_vote.winning_answer,
-        finalized_at: clock.unix_timestamp as u64,
-    });
+    match outcome {
+        Ok(winning_answer) => {
+            answer_vote.winning_answer = winning_answer;
+            governance_item.answer_result = winning_answer;
+            governance_item.pays_reward_pool =
+                governance_item.reaches_reward_pool(config.min_reward_pool);
+
+            emit!(AnswerFinalized {
+                quest_key,
+                winning_answer,
+                total_voted: answer_vote.total_voted,
+                answer_end_time: governance_item.answer_end_time,
+                finalized_at: clock.unix_timestamp as u64,
+            });
+        }
+        Err(reason) => {
+            // Same end state as cancel_answer: finalized with no winner
+            answer_vote.winning_answer = 0;
+            governance_item.answer_result = 0;
+
+            emit!(AnswerCancelled {
+                quest_key,
+                reason,
+                note: String::new(),
+                total_voted: answer_vote.total_voted,
+                cancelled_by: finalized_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
 
     Ok(())
 }
@@ -72,6 +203,8 @@ pub fn finalize_answer(
 pub struct AnswerFinalized {
     pub quest_key: u64,
     pub winning_answer: u64,
+    pub total_voted: u64,
+    pub answer_end_time: i64,
     pub finalized_at: u64,
 }
 
@@ -1,4 +1,4 @@
-use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_SEED, TREASURY_SEED};
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_CONFIG_VERSION, GOVERNANCE_SEED, TREASURY_SEED};
 use crate::states::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
@@ -80,6 +80,7 @@ pub fn initialize(
     config.constant_reward_token = constant_reward_token;
     config.total_governance = 0;
     config.bump = ctx.bumps.config;
+    config.version = GOVERNANCE_CONFIG_VERSION;
 
     governance.config = config.key();
     governance.total_items = 0;
@@ -88,5 +89,32 @@ pub fn initialize(
     governance.total_rewards_distributed = 0;
     governance.bump = ctx.bumps.governance;
 
+    emit!(GovernanceInitialized {
+        authority: config.authority,
+        base_token_mint: config.base_token_mint,
+        base_nft_collection: config.base_nft_collection,
+        min_total_vote,
+        max_total_vote,
+        min_required_nft,
+        max_votable_nft,
+        duration_hours,
+        constant_reward_token,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct GovernanceInitialized {
+    pub authority: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub base_nft_collection: Pubkey,
+    pub min_total_vote: u64,
+    pub max_total_vote: u64,
+    pub min_required_nft: u8,
+    pub max_votable_nft: u8,
+    pub duration_hours: u64,
+    pub constant_reward_token: u64,
+    pub timestamp: i64,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, DECISION_VOTE_SEED, ANSWER_VOTE_SEED};
+use crate::utils::require_quorum;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, DECISION_VOTE_SEED, ANSWER_VOTE_SEED, SUPPLY_CHECKPOINTS_SEED};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -13,6 +14,13 @@ pub struct MakeDecisionAndExecuteAnswer<'info> {
     )]
     pub config: Account<'info, GovernanceConfig>,
 
+    /// Voting supply history, read at the item's start_slot for the bps thresholds
+    #[account(
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Account<'info, SupplyCheckpoints>,
+
     #[account(
         mut,
         seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
@@ -63,10 +71,15 @@ pub fn make_decision_answer(
         GovernanceError::VotingPeriodNotEnded
     );
 
-    require!(
-        decision_vote.total_voted >= config.min_total_vote,
-        GovernanceError::InsufficientVotes
-    );
+    // Tie-break by the authority still needs quorum; the approval threshold does not apply
+    let voting_supply = governance_item.voting_supply(&ctx.accounts.supply_checkpoints)?;
+    require_quorum(
+        config,
+        decision_vote.total_voted,
+        governance_item.decision_nft_turnout,
+        decision_vote.count_success + decision_vote.count_adjourn,
+        voting_supply,
+    )?;
 
     require!(
         decision_vote.count_success == decision_vote.count_adjourn,
@@ -83,7 +96,7 @@ pub fn make_decision_answer(
 
     governance_item.answer_start_time = current_time;
     governance_item.answer_end_time = end_time;
-    governance_item.answer_keys = answer_keys;
+    governance_item.answer_keys = answer_keys.clone();
 
     answer_vote.quest_key = quest_key;
     answer_vote.total_voted = 0;
@@ -95,11 +108,17 @@ pub fn make_decision_answer(
         quest_key,
         created_at: current_time as u64,
         end_at: end_time as u64,
+        answer_keys,
     });
 
     emit!(DecisionResultMade {
         quest_key,
-        result: "success".to_string(),
+        result: DecisionResult::Success,
+        count_success: decision_vote.count_success,
+        count_adjourn: decision_vote.count_adjourn,
+        total_voted: decision_vote.total_voted,
+        decision_end_time: governance_item.decision_end_time,
+        finalized_at: clock.unix_timestamp,
     });
 
     Ok(())
@@ -108,5 +127,10 @@ pub fn make_decision_answer(
 #[event]
 pub struct DecisionResultMade {
     pub quest_key: u64,
-    pub result: String,
+    pub result: DecisionResult,
+    pub count_success: u64,
+    pub count_adjourn: u64,
+    pub total_voted: u64,
+    pub decision_end_time: i64,
+    pub finalized_at: i64,
 }
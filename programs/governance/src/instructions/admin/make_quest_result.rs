@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED};
+use crate::utils::require_quorum;
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED, SUPPLY_CHECKPOINTS_SEED};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -13,6 +14,13 @@ pub struct MakeQuestResult<'info> {
     )]
     pub config: Account<'info, GovernanceConfig>,
 
+    /// Voting supply history, read at the item's start_slot for the bps thresholds
+    #[account(
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Account<'info, SupplyCheckpoints>,
+
     #[account(
         mut,
         seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
@@ -55,10 +63,15 @@ pub fn make_quest_result(ctx: Context<MakeQuestResult>, quest_key: u64) -> Resul
         GovernanceError::VotingPeriodNotEnded
     );
 
-    require!(
-        quest_vote.total_voted >= config.min_total_vote,
-        GovernanceError::InsufficientVotes
-    );
+    // Tie-break by the authority still needs quorum; the approval threshold does not apply
+    let voting_supply = governance_item.voting_supply(&ctx.accounts.supply_checkpoints)?;
+    require_quorum(
+        config,
+        quest_vote.total_voted,
+        governance_item.quest_nft_turnout,
+        quest_vote.count_approver + quest_vote.count_rejector,
+        voting_supply,
+    )?;
 
     require!(!quest_vote.finalized, GovernanceError::AlreadyFinalized);
 
@@ -73,7 +86,12 @@ pub fn make_quest_result(ctx: Context<MakeQuestResult>, quest_key: u64) -> Resul
 
     emit!(QuestResultMade {
         quest_key,
-        result: "approve".to_string(),
+        result: QuestResult::Approved,
+        count_approver: quest_vote.count_approver,
+        count_rejector: quest_vote.count_rejector,
+        total_voted: quest_vote.total_voted,
+        quest_end_time: governance_item.quest_end_time,
+        finalized_at: clock.unix_timestamp,
     });
 
     Ok(())
@@ -82,5 +100,10 @@ pub fn make_quest_result(ctx: Context<MakeQuestResult>, quest_key: u64) -> Resul
 #[event]
 pub struct QuestResultMade {
     pub quest_key: u64,
-    pub result: String,
+    pub result: QuestResult,
+    pub count_approver: u64,
+    pub count_rejector: u64,
+    pub total_voted: u64,
+    pub quest_end_time: i64,
+    pub finalized_at: i64,
 }
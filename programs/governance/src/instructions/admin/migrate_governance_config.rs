@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_CONFIG_VERSION};
+use crate::utils::realloc_account;
+
+#[derive(Accounts)]
+pub struct MigrateGovernanceConfig<'info> {
+    /// CHECK: May still use an older layout, so it is deserialized by hand after the owner and seeds checks
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_governance_config(ctx: Context<MigrateGovernanceConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let new_len = ACCOUNT_DISCRIMINATOR + GovernanceConfig::INIT_SPACE;
+
+    // Accounts smaller than the current layout predate versioning
+    let mut config = {
+        let data = config_info.try_borrow_data()?;
+        if data.len() < new_len {
+            require!(
+                data.len() >= ACCOUNT_DISCRIMINATOR
+                    && data[..ACCOUNT_DISCRIMINATOR] == *GovernanceConfig::DISCRIMINATOR,
+                GovernanceError::InvalidAccountData
+            );
+            GovernanceConfig::from(GovernanceConfigV0::deserialize(
+                &mut &data[ACCOUNT_DISCRIMINATOR..],
+            )?)
+        } else {
+            GovernanceConfig::try_deserialize(&mut &data[..])?
+        }
+    };
+
+    require!(
+        config.authority == ctx.accounts.authority.key(),
+        GovernanceError::Unauthorized
+    );
+
+    let from_version = config.version;
+    require!(
+        from_version < GOVERNANCE_CONFIG_VERSION,
+        GovernanceError::AccountAlreadyMigrated
+    );
+
+    config.version = GOVERNANCE_CONFIG_VERSION;
+
+    realloc_account(
+        config_info.clone(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    {
+        let mut data = config_info.try_borrow_mut_data()?;
+        config.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(GovernanceConfigMigrated {
+        from_version,
+        to_version: GOVERNANCE_CONFIG_VERSION,
+        migrated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!(
+        "Governance config migrated from version {} to {}",
+        from_version,
+        GOVERNANCE_CONFIG_VERSION
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceConfigMigrated {
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+}
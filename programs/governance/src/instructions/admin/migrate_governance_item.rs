@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{
+    ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_VERSION, SUPPLY_CHECKPOINTS_SEED,
+};
+use crate::utils::realloc_account;
+
+#[derive(Accounts)]
+pub struct MigrateGovernanceItem<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: May still use an older layout, so it is deserialized by hand after the owner check
+    #[account(mut, owner = crate::ID)]
+    pub governance_item: UncheckedAccount<'info>,
+
+    /// Global supply history; the staked supply is recorded on items that predate it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR + SupplyCheckpoints::INIT_SPACE,
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_governance_item(ctx: Context<MigrateGovernanceItem>) -> Result<()> {
+    let item_info = ctx.accounts.governance_item.to_account_info();
+    let new_len = ACCOUNT_DISCRIMINATOR + GovernanceItem::INIT_SPACE;
+
+    // Accounts smaller than the current layout predate versioning
+    let mut governance_item = {
+        let data = item_info.try_borrow_data()?;
+        if data.len() < new_len {
+            require!(
+                data.len() >= ACCOUNT_DISCRIMINATOR
+                    && data[..ACCOUNT_DISCRIMINATOR] == *GovernanceItem::DISCRIMINATOR,
+                GovernanceError::InvalidAccountData
+            );
+            GovernanceItem::from(GovernanceItemV0::deserialize(
+                &mut &data[ACCOUNT_DISCRIMINATOR..],
+            )?)
+        } else {
+            GovernanceItem::try_deserialize(&mut &data[..])?
+        }
+    };
+
+    let from_version = governance_item.version;
+    require!(
+        from_version < GOVERNANCE_ITEM_VERSION,
+        GovernanceError::AccountAlreadyMigrated
+    );
+
+    governance_item.version = GOVERNANCE_ITEM_VERSION;
+
+    // Legacy items started before the supply history, which cannot be looked up once it is
+    // truncated; record the supply at their start_slot if still known, the current one otherwise
+    let supply_checkpoints = &mut ctx.accounts.supply_checkpoints;
+    if supply_checkpoints.checkpoints.is_empty() {
+        supply_checkpoints.bump = ctx.bumps.supply_checkpoints;
+    }
+    if governance_item.voting_supply == 0 {
+        governance_item.voting_supply = match supply_checkpoints.get_past_supply(governance_item.start_slot) {
+            Ok(supply) if supply > 0 => supply,
+            _ => supply_checkpoints.staked(),
+        };
+    }
+
+    realloc_account(
+        item_info.clone(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    {
+        let mut data = item_info.try_borrow_mut_data()?;
+        governance_item.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(GovernanceItemMigrated {
+        quest_key: governance_item.quest_key,
+        from_version,
+        to_version: GOVERNANCE_ITEM_VERSION,
+    });
+
+    msg!(
+        "Governance item {} migrated from version {} to {}",
+        governance_item.quest_key,
+        from_version,
+        GOVERNANCE_ITEM_VERSION
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceItemMigrated {
+    pub quest_key: u64,
+    pub from_version: u8,
+    pub to_version: u8,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, PROPOSAL_VOTER_SEED};
+use crate::utils::realloc_account;
+
+#[derive(Accounts)]
+pub struct MigrateProposal<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    /// CHECK: May still use the layout without tallies, so it is deserialized by hand after the owner check
+    #[account(mut, owner = crate::ID)]
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites a proposal created before the Yes and No tallies in the current layout.
+/// `remaining_accounts` must hold every ProposalVoterRecord of the proposal; the tallies are
+/// rebuilt from their votes, which have to add up to `total_vote`.
+pub fn migrate_proposal(ctx: Context<MigrateProposal>) -> Result<()> {
+    let proposal_info = ctx.accounts.proposal.to_account_info();
+    let new_len = ACCOUNT_DISCRIMINATOR + Proposal::INIT_SPACE;
+
+    let mut proposal = {
+        let data = proposal_info.try_borrow_data()?;
+        require!(data.len() < new_len, GovernanceError::AccountAlreadyMigrated);
+        require!(
+            data.len() >= ACCOUNT_DISCRIMINATOR
+                && data[..ACCOUNT_DISCRIMINATOR] == *Proposal::DISCRIMINATOR,
+            GovernanceError::InvalidAccountData
+        );
+        Proposal::from(ProposalV0::deserialize(&mut &data[ACCOUNT_DISCRIMINATOR..])?)
+    };
+
+    let proposal_key_bytes = proposal.proposal_key.to_le_bytes();
+    let mut counted: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    for record_info in ctx.remaining_accounts {
+        require!(
+            !counted.contains(record_info.key),
+            GovernanceError::ProposalVotesMismatch
+        );
+        require!(record_info.owner == &crate::ID, GovernanceError::ProposalVotesMismatch);
+        let record = ProposalVoterRecord::try_deserialize(&mut &record_info.try_borrow_data()?[..])?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[PROPOSAL_VOTER_SEED, proposal_key_bytes.as_ref(), record.voter.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(record_info.key(), expected, GovernanceError::ProposalVotesMismatch);
+
+        let tally = match record.vote_choice {
+            ProposalResult::Yes => &mut proposal.yes_vote,
+            _ => &mut proposal.no_vote,
+        };
+        *tally = tally
+            .checked_add(record.vote_count as u16)
+            .ok_or(GovernanceError::MathOverflow)?;
+        counted.push(record_info.key());
+    }
+
+    let tallied = proposal
+        .yes_vote
+        .checked_add(proposal.no_vote)
+        .ok_or(GovernanceError::MathOverflow)?;
+    require!(
+        tallied == proposal.total_vote,
+        GovernanceError::ProposalVotesMismatch
+    );
+
+    realloc_account(
+        proposal_info.clone(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        new_len,
+    )?;
+
+    {
+        let mut data = proposal_info.try_borrow_mut_data()?;
+        proposal.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(ProposalMigrated {
+        proposal_key: proposal.proposal_key,
+        yes_vote: proposal.yes_vote,
+        no_vote: proposal.no_vote,
+    });
+
+    msg!(
+        "Proposal {} migrated with {} yes and {} no votes",
+        proposal.proposal_key,
+        proposal.yes_vote,
+        proposal.no_vote
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct ProposalMigrated {
+    pub proposal_key: u64,
+    pub yes_vote: u16,
+    pub no_vote: u16,
+}
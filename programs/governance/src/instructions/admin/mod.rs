@@ -7,19 +7,28 @@ pub mod finalize_answer;
 pub mod initialize;
 pub mod make_decision_answer;
 pub mod make_quest_result;
+pub mod migrate_governance_config;
+pub mod migrate_governance_item;
+pub mod migrate_proposal;
 pub mod pause;
 pub mod set_answer;
+pub mod set_answer_commit_reveal;
 pub mod set_answer_end_time;
+pub mod set_answer_tie_break;
 pub mod set_decision_answer;
 pub mod set_decision_end_time;
 pub mod set_max_votes;
+pub mod set_min_reward_pool;
 pub mod set_minimum_nfts;
+pub mod set_phase_rewards;
 pub mod set_proposal_result;
 pub mod set_quest_duration;
 pub mod set_quest_end_time;
 pub mod set_quest_result;
 pub mod set_reward_amount;
 pub mod set_total_vote;
+pub mod set_vote_thresholds;
+pub mod set_vote_weighting;
 pub mod withdraw_tokens;
 pub mod update_base_token_mint;
 pub mod update_collection;
@@ -33,19 +42,28 @@ pub use finalize_answer::*;
 pub use initialize::*;
 pub use make_decision_answer::*;
 pub use make_quest_result::*;
+pub use migrate_governance_config::*;
+pub use migrate_governance_item::*;
+pub use migrate_proposal::*;
 pub use pause::*;
 pub use set_answer::*;
+pub use set_answer_commit_reveal::*;
 pub use set_answer_end_time::*;
+pub use set_answer_tie_break::*;
 pub use set_decision_answer::*;
 pub use set_decision_end_time::*;
 pub use set_max_votes::*;
+pub use set_min_reward_pool::*;
 pub use set_minimum_nfts::*;
+pub use set_phase_rewards::*;
 pub use set_proposal_result::*;
 pub use set_quest_duration::*;
 pub use set_quest_end_time::*;
 pub use set_quest_result::*;
 pub use set_reward_amount::*;
 pub use set_total_vote::*;
+pub use set_vote_thresholds::*;
+pub use set_vote_weighting::*;
 pub use withdraw_tokens::*;
 pub use update_base_token_mint::*;
 pub use update_collection::*;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
+use crate::utils::validate_answer_keys;
 use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, ANSWER_VOTE_SEED};
 
 #[derive(Accounts)]
@@ -58,17 +59,32 @@ pub fn set_answer(
         GovernanceError::AnswerVoteFinalized
     );
 
-    require!(!answer_keys.is_empty(), GovernanceError::InvalidAnswerKeys);
+    // Tallies are counted per key, so the options are fixed once voting begins
+    require!(
+        answer_vote.total_voted == 0,
+        GovernanceError::InvalidPhase
+    );
 
-    let winning_answer = answer_keys[0];
+    validate_answer_keys(&answer_keys)?;
 
-    // Update governance item - set to non-zero to enable voting
-    // Use the first answer key as the initial value
-    governance_item.answer_result = winning_answer;
+    // The winner is decided by finalize_answer from the vote tallies
+    governance_item.answer_keys = answer_keys.clone();
+    governance_item.answer_result = 0;
 
-    // Reset voting state - finalized will be set after answer phase completes
-    answer_vote.winning_answer = 0;
-    answer_vote.finalized = false;
+    emit!(AnswerResultSet {
+        quest_key,
+        answer_keys,
+        set_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
+}
+
+#[event]
+pub struct AnswerResultSet {
+    pub quest_key: u64,
+    pub answer_keys: Vec<u64>,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
 }
\ No newline at end of file
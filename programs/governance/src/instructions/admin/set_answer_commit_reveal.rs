@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct SetAnswerCommitReveal<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump,
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Switches the item's answer phase to commit-reveal voting with a reveal window of
+/// `reveal_hours` after answer_end_time, or back to open voting with 0.
+/// Only possible before the answer phase starts.
+pub fn set_answer_commit_reveal(
+    ctx: Context<SetAnswerCommitReveal>,
+    quest_key: u64,
+    reveal_hours: u64,
+) -> Result<()> {
+    let governance_item = &mut ctx.accounts.governance_item;
+
+    require!(
+        governance_item.answer_start_time == 0,
+        GovernanceError::InvalidPhase
+    );
+
+    let reveal_duration = reveal_hours
+        .checked_mul(3600)
+        .and_then(|seconds| i64::try_from(seconds).ok())
+        .ok_or(GovernanceError::MathOverflow)?;
+    governance_item.reveal_duration = reveal_duration;
+
+    emit!(AnswerCommitRevealSet {
+        quest_key,
+        reveal_duration,
+        set_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AnswerCommitRevealSet {
+    pub quest_key: u64,
+    /// Reveal window in seconds; 0 disables commit-reveal
+    pub reveal_duration: i64,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::GOVERNANCE_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetAnswerTieBreak<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets how finalize_answer resolves options tied for the most votes
+pub fn set_answer_tie_break(
+    ctx: Context<SetAnswerTieBreak>,
+    tie_break: AnswerTieBreak,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let old_tie_break = config.answer_tie_break;
+    config.answer_tie_break = tie_break;
+
+    emit!(AnswerTieBreakUpdated {
+        old_tie_break,
+        new_tie_break: tie_break,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!("Answer tie-break updated: {:?} -> {:?}", old_tie_break, tie_break);
+
+    Ok(())
+}
+
+#[event]
+pub struct AnswerTieBreakUpdated {
+    pub old_tie_break: AnswerTieBreak,
+    pub new_tie_break: AnswerTieBreak,
+    pub updated_by: Pubkey,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, DECISION_VOTE_SEED, ANSWER_VOTE_SEED};
+use crate::utils::{require_quorum, tally_passes};
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, DECISION_VOTE_SEED, ANSWER_VOTE_SEED, SUPPLY_CHECKPOINTS_SEED};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -12,6 +13,13 @@ pub struct SetDecisionAndExecuteAnswer<'info> {
         constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
     )]
     pub config: Account<'info, GovernanceConfig>,
+
+    /// Voting supply history, read at the item's start_slot for the bps thresholds
+    #[account(
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Account<'info, SupplyCheckpoints>,
     
     #[account(
         mut,
@@ -63,12 +71,22 @@ pub fn set_decision_answer(
         GovernanceError::VotingPeriodNotEnded
     );
     
-    require!(
-        decision_vote.total_voted >= config.min_total_vote,
-        GovernanceError::InsufficientVotes
-    );
+    let voting_supply = governance_item.voting_supply(&ctx.accounts.supply_checkpoints)?;
+    require_quorum(
+        config,
+        decision_vote.total_voted,
+        governance_item.decision_nft_turnout,
+        decision_vote.count_success + decision_vote.count_adjourn,
+        voting_supply,
+    )?;
     
-    let result = if decision_vote.count_success > decision_vote.count_adjourn {
+    let result = if tally_passes(
+        config.approval_bps,
+        decision_vote.count_success,
+        decision_vote.count_adjourn,
+        governance_item.decision_nft_turnout,
+        voting_supply,
+    ) {
         DecisionResult::Success
     } else {
         DecisionResult::Adjourn
@@ -83,7 +101,7 @@ pub fn set_decision_answer(
         
         governance_item.answer_start_time = current_time;
         governance_item.answer_end_time = end_time;
-        governance_item.answer_keys = answer_keys;
+        governance_item.answer_keys = answer_keys.clone();
         
         answer_vote.quest_key = quest_key;
         answer_vote.total_voted = 0;
@@ -95,12 +113,18 @@ pub fn set_decision_answer(
             quest_key,
             created_at: current_time as u64,
             end_at: end_time as u64,
+            answer_keys,
         });
     }
     
     emit!(DecisionResultSet {
         quest_key,
-        result: if result == DecisionResult::Success { "success".to_string() } else { "adjourn".to_string() },
+        result,
+        count_success: decision_vote.count_success,
+        count_adjourn: decision_vote.count_adjourn,
+        total_voted: decision_vote.total_voted,
+        decision_end_time: governance_item.decision_end_time,
+        finalized_at: clock.unix_timestamp,
     });
     
     Ok(())
@@ -109,7 +133,12 @@ pub fn set_decision_answer(
 #[event]
 pub struct DecisionResultSet {
     pub quest_key: u64,
-    pub result: String,
+    pub result: DecisionResult,
+    pub count_success: u64,
+    pub count_adjourn: u64,
+    pub total_voted: u64,
+    pub decision_end_time: i64,
+    pub finalized_at: i64,
 }
 
 #[event]
@@ -117,4 +146,5 @@ pub struct AnswerStarted {
     pub quest_key: u64,
     pub created_at: u64,
     pub end_at: u64,
+    pub answer_keys: Vec<u64>,
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::GOVERNANCE_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetMinRewardPool<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets the smallest reward pool that switches a quest's answer rewards from the constant
+/// per-vote reward to a share of the pool. 0 keeps every quest on the constant reward.
+pub fn set_min_reward_pool(ctx: Context<SetMinRewardPool>, min_reward_pool: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let old_amount = config.min_reward_pool;
+    config.min_reward_pool = min_reward_pool;
+
+    emit!(MinRewardPoolUpdated {
+        old_amount,
+        new_amount: min_reward_pool,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!(
+        "Minimum reward pool updated from {} to {}",
+        old_amount,
+        min_reward_pool
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct MinRewardPoolUpdated {
+    pub old_amount: u64,
+    pub new_amount: u64,
+    pub updated_by: Pubkey,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::GOVERNANCE_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetPhaseRewards<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets the per-vote rewards paid from the treasury to voters who sided with the
+/// majority in the quest and decision phases. 0 disables a phase's reward.
+pub fn set_phase_rewards(
+    ctx: Context<SetPhaseRewards>,
+    quest_reward_token: u64,
+    decision_reward_token: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.quest_reward_token = quest_reward_token;
+    config.decision_reward_token = decision_reward_token;
+
+    emit!(PhaseRewardsUpdated {
+        quest_reward_token,
+        decision_reward_token,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!(
+        "Phase rewards updated: quest {}, decision {}",
+        quest_reward_token,
+        decision_reward_token
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct PhaseRewardsUpdated {
+    pub quest_reward_token: u64,
+    pub decision_reward_token: u64,
+    pub updated_by: Pubkey,
+}
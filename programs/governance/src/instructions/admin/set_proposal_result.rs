@@ -32,11 +32,11 @@ pub struct SetProposalResult<'info> {
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Finalizes a proposal once voting has ended. It passes with more Yes than No votes,
+/// ties and proposals nobody voted on fail; `result_vote` records the winning side's tally.
 pub fn set_proposal_result(
     ctx: Context<SetProposalResult>,
     proposal_key: u64,
-    result: ProposalResult,
-    result_vote: u16,
 ) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
     let governance = &mut ctx.accounts.governance;
@@ -52,15 +52,11 @@ pub fn set_proposal_result(
         GovernanceError::VotingPeriodNotEnded
     );
 
-    require!(
-        result != ProposalResult::Pending,
-        GovernanceError::InvalidParameter
-    );
-
-    require!(
-        result_vote <= proposal.total_vote,
-        GovernanceError::InvalidParameter
-    );
+    let (result, result_vote) = if proposal.yes_vote > proposal.no_vote {
+        (ProposalResult::Yes, proposal.yes_vote)
+    } else {
+        (ProposalResult::No, proposal.no_vote)
+    };
 
     proposal.result = result;
     proposal.result_vote = result_vote;
@@ -72,6 +68,8 @@ pub fn set_proposal_result(
         proposal_key,
         result,
         result_vote,
+        yes_vote: proposal.yes_vote,
+        no_vote: proposal.no_vote,
         total_vote: proposal.total_vote,
         finalized_at: clock.unix_timestamp,
     });
@@ -84,6 +82,8 @@ pub struct ProposalResultSet {
     pub proposal_key: u64,
     pub result: ProposalResult,
     pub result_vote: u16,
+    pub yes_vote: u16,
+    pub no_vote: u16,
     pub total_vote: u16,
     pub finalized_at: i64,
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED};
+use crate::utils::{require_quorum, tally_passes};
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED, SUPPLY_CHECKPOINTS_SEED};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -12,6 +13,13 @@ pub struct SetQuestResult<'info> {
         constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
     )]
     pub config: Account<'info, GovernanceConfig>,
+
+    /// Voting supply history, read at the item's start_slot for the bps thresholds
+    #[account(
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Account<'info, SupplyCheckpoints>,
     
     #[account(
         mut,
@@ -55,14 +63,24 @@ pub fn set_quest_result(ctx: Context<SetQuestResult>, quest_key: u64) -> Result<
         GovernanceError::VotingPeriodNotEnded
     );
     
-    require!(
-        quest_vote.total_voted >= config.min_total_vote,
-        GovernanceError::InsufficientVotes
-    );
+    let voting_supply = governance_item.voting_supply(&ctx.accounts.supply_checkpoints)?;
+    require_quorum(
+        config,
+        quest_vote.total_voted,
+        governance_item.quest_nft_turnout,
+        quest_vote.count_approver + quest_vote.count_rejector,
+        voting_supply,
+    )?;
     
     require!(!quest_vote.finalized, GovernanceError::AlreadyFinalized);
     
-    let result = if quest_vote.count_approver > quest_vote.count_rejector {
+    let result = if tally_passes(
+        config.approval_bps,
+        quest_vote.count_approver,
+        quest_vote.count_rejector,
+        governance_item.quest_nft_turnout,
+        voting_supply,
+    ) {
         QuestResult::Approved
     } else {
         QuestResult::Rejected
@@ -79,7 +97,12 @@ pub fn set_quest_result(ctx: Context<SetQuestResult>, quest_key: u64) -> Result<
     
     emit!(QuestResultSet {
         quest_key,
-        result: if result == QuestResult::Approved { "approve".to_string() } else { "reject".to_string() },
+        result,
+        count_approver: quest_vote.count_approver,
+        count_rejector: quest_vote.count_rejector,
+        total_voted: quest_vote.total_voted,
+        quest_end_time: governance_item.quest_end_time,
+        finalized_at: clock.unix_timestamp,
     });
     
     Ok(())
@@ -88,5 +111,10 @@ pub fn set_quest_result(ctx: Context<SetQuestResult>, quest_key: u64) -> Result<
 #[event]
 pub struct QuestResultSet {
     pub quest_key: u64,
-    pub result: String,
+    pub result: QuestResult,
+    pub count_approver: u64,
+    pub count_rejector: u64,
+    pub total_voted: u64,
+    pub quest_end_time: i64,
+    pub finalized_at: i64,
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{BPS_DENOMINATOR, GOVERNANCE_CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct SetVoteThresholds<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets the quorum and approval thresholds, both in bps of the staked supply at an item's start_slot
+/// and checked against the staked NFTs behind the votes rather than their weight.
+/// They apply on top of `min_total_vote`; 0 disables a threshold.
+pub fn set_vote_thresholds(
+    ctx: Context<SetVoteThresholds>,
+    quorum_bps: u16,
+    approval_bps: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        quorum_bps as u64 <= BPS_DENOMINATOR && approval_bps as u64 <= BPS_DENOMINATOR,
+        GovernanceError::InvalidBps
+    );
+
+    let old_quorum_bps = config.quorum_bps;
+    let old_approval_bps = config.approval_bps;
+    config.quorum_bps = quorum_bps;
+    config.approval_bps = approval_bps;
+
+    emit!(VoteThresholdsUpdated {
+        old_quorum_bps,
+        new_quorum_bps: quorum_bps,
+        old_approval_bps,
+        new_approval_bps: approval_bps,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!(
+        "Vote thresholds updated: quorum {} -> {} bps, approval {} -> {} bps",
+        old_quorum_bps,
+        quorum_bps,
+        old_approval_bps,
+        approval_bps
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteThresholdsUpdated {
+    pub old_quorum_bps: u16,
+    pub new_quorum_bps: u16,
+    pub old_approval_bps: u16,
+    pub new_approval_bps: u16,
+    pub updated_by: Pubkey,
+}
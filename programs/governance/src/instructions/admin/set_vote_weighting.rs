@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::GOVERNANCE_CONFIG_SEED;
+
+#[derive(Accounts)]
+pub struct SetVoteWeighting<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ GovernanceError::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets the curve turning NFT counts into votes for quest, answer and proposal voting.
+/// Votes already cast keep the weight they were counted with.
+pub fn set_vote_weighting(
+    ctx: Context<SetVoteWeighting>,
+    vote_weighting: VoteWeighting,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let old_weighting = config.vote_weighting;
+    config.vote_weighting = vote_weighting;
+
+    emit!(VoteWeightingUpdated {
+        old_weighting,
+        new_weighting: vote_weighting,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!("Vote weighting updated: {:?} -> {:?}", old_weighting, vote_weighting);
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteWeightingUpdated {
+    pub old_weighting: VoteWeighting,
+    pub new_weighting: VoteWeighting,
+    pub updated_by: Pubkey,
+}
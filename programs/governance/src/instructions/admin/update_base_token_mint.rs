@@ -46,18 +46,27 @@ pub struct UpdateBaseTokenMint<'info> {
 
 pub fn update_base_token_mint(ctx: Context<UpdateBaseTokenMint>) -> Result<()> {
     let config = &mut ctx.accounts.config;
-    
-    msg!(
-        "Updating base_token_mint from {} to {}", 
-        config.base_token_mint, 
-        ctx.accounts.new_base_token_mint.key()
-    );
-    
+    let old_mint = config.base_token_mint;
+
     // Update the base token mint
     config.base_token_mint = ctx.accounts.new_base_token_mint.key();
-    
-    msg!("Base token mint updated successfully");
-    msg!("New treasury token account: {}", ctx.accounts.new_treasury_token_account.key());
-    
+
+    emit!(BaseTokenMintUpdated {
+        old_mint,
+        new_mint: config.base_token_mint,
+        treasury_token_account: ctx.accounts.new_treasury_token_account.key(),
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct BaseTokenMintUpdated {
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
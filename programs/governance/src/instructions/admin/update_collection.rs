@@ -83,9 +83,9 @@ pub fn update_collection(
         ),
         None,
         Some(mpl_token_metadata::types::DataV2 {
-            name,
-            symbol,
-            uri,
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
             seller_fee_basis_points: 0,
             creators: None,
             collection: None,
@@ -95,5 +95,24 @@ pub fn update_collection(
         None, 
     )?;
 
+    emit!(CollectionUpdated {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name,
+        symbol,
+        uri,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct CollectionUpdated {
+    pub collection_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
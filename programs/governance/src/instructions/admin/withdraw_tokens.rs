@@ -72,11 +72,24 @@ pub fn withdraw_tokens(ctx: Context<WithdrawTokens>, amount: u64) -> Result<()>
 
     token::transfer(cpi_ctx, amount)?;
 
-    msg!(
-        "Withdrawn {} tokens from treasury to {}",
+    ctx.accounts.treasury_token_account.reload()?;
+
+    emit!(TokensWithdrawn {
         amount,
-        ctx.accounts.destination_token_account.key()
-    );
+        destination: ctx.accounts.destination_token_account.key(),
+        withdrawn_by: ctx.accounts.authority.key(),
+        treasury_balance: ctx.accounts.treasury_token_account.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
+}
+
+#[event]
+pub struct TokensWithdrawn {
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub withdrawn_by: Pubkey,
+    pub treasury_balance: u64,
+    pub timestamp: i64,
 }
\ No newline at end of file
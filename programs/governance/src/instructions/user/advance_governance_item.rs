@@ -0,0 +1,361 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::utils::{create_pda_account, require_quorum, tally_passes};
+use crate::constant::{
+    ACCOUNT_DISCRIMINATOR, ANSWER_VOTE_SEED, DECISION_VOTE_SEED, GOVERNANCE_CONFIG_SEED,
+    GOVERNANCE_ITEM_SEED, GOVERNANCE_SEED, QUEST_VOTE_SEED, SUPPLY_CHECKPOINTS_SEED,
+};
+use crate::instructions::admin::finalize_answer::finalize_answer_vote;
+use crate::instructions::admin::set_decision_answer::{AnswerStarted, DecisionResultSet};
+use crate::instructions::admin::set_quest_result::QuestResultSet;
+use crate::instructions::user::start_decision::DecisionStarted;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GovernanceTransition {
+    QuestFinalized,
+    DecisionStarted,
+    DecisionFinalized,
+    AnswerFinalized,
+}
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct AdvanceGovernanceItem<'info> {
+    /// Anyone may advance an item; pays the rent of the vote account a new phase needs
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Box<Account<'info, GovernanceItem>>,
+
+    #[account(
+        mut,
+        seeds = [QUEST_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = quest_vote.bump
+    )]
+    pub quest_vote: Account<'info, QuestVote>,
+
+    /// CHECK: Created by this instruction when the decision phase opens, read afterwards
+    #[account(
+        mut,
+        seeds = [DECISION_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub decision_vote: UncheckedAccount<'info>,
+
+    /// CHECK: Created by this instruction when the answer phase opens, read afterwards
+    #[account(
+        mut,
+        seeds = [ANSWER_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub answer_vote: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Performs the next phase transition of an item once it is fully determined by
+/// on-chain tallies and timestamps:
+/// - quest vote ended without a tie: Approved or Rejected
+/// - quest approved: open the decision vote
+/// - decision vote ended without a tie: Success (opening the answer phase with the
+///   creator's answer options) or Adjourn
+/// - answer vote closed: the winning answer, or a cancellation, exactly as finalize_answer;
+///   `remaining_accounts` holds the answer option PDAs in `answer_keys` order
+///
+/// Ties, cancellations and answer keys chosen by the authority stay with the admin instructions.
+pub fn advance_governance_item(mut ctx: Context<AdvanceGovernanceItem>, quest_key: u64) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GovernanceError::GovernancePaused);
+
+    let governance_item = &ctx.accounts.governance_item;
+    let transition = if governance_item.quest_result == QuestResult::Pending {
+        finalize_quest(&mut ctx, quest_key)?;
+        GovernanceTransition::QuestFinalized
+    } else if governance_item.quest_result == QuestResult::Approved
+        && governance_item.decision_start_time == 0
+    {
+        start_decision(&mut ctx, quest_key)?;
+        GovernanceTransition::DecisionStarted
+    } else if governance_item.quest_result == QuestResult::Approved
+        && governance_item.decision_result == DecisionResult::Pending
+    {
+        finalize_decision(&mut ctx, quest_key)?;
+        GovernanceTransition::DecisionFinalized
+    } else if governance_item.decision_result == DecisionResult::Success {
+        finalize_answer(&mut ctx)?;
+        GovernanceTransition::AnswerFinalized
+    } else {
+        return err!(GovernanceError::NothingToAdvance);
+    };
+
+    emit!(GovernanceItemAdvanced {
+        quest_key,
+        transition,
+        advanced_by: ctx.accounts.cranker.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn finalize_quest(ctx: &mut Context<AdvanceGovernanceItem>, quest_key: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let quest_vote = &mut ctx.accounts.quest_vote;
+    let governance = &mut ctx.accounts.governance;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp > governance_item.quest_end_time,
+        GovernanceError::VotingPeriodNotEnded
+    );
+
+    let voting_supply = governance_item.voting_supply(&ctx.accounts.supply_checkpoints)?;
+    require_quorum(
+        config,
+        quest_vote.total_voted,
+        governance_item.quest_nft_turnout,
+        quest_vote.count_approver + quest_vote.count_rejector,
+        voting_supply,
+    )?;
+
+    require!(!quest_vote.finalized, GovernanceError::AlreadyFinalized);
+    require!(
+        quest_vote.count_approver != quest_vote.count_rejector,
+        GovernanceError::TiedVoteNeedsAuthority
+    );
+
+    let result = if tally_passes(
+        config.approval_bps,
+        quest_vote.count_approver,
+        quest_vote.count_rejector,
+        governance_item.quest_nft_turnout,
+        voting_supply,
+    ) {
+        QuestResult::Approved
+    } else {
+        QuestResult::Rejected
+    };
+
+    governance_item.quest_result = result.clone();
+    quest_vote.finalized = true;
+    quest_vote.total_voted = quest_vote.count_approver + quest_vote.count_rejector;
+
+    if result == QuestResult::Rejected {
+        governance.active_items -= 1;
+        governance.completed_items += 1;
+    }
+
+    emit!(QuestResultSet {
+        quest_key,
+        result,
+        count_approver: quest_vote.count_approver,
+        count_rejector: quest_vote.count_rejector,
+        total_voted: quest_vote.total_voted,
+        quest_end_time: governance_item.quest_end_time,
+        finalized_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn start_decision(ctx: &mut Context<AdvanceGovernanceItem>, quest_key: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let clock = Clock::get()?;
+
+    let quest_key_bytes = quest_key.to_le_bytes();
+    let bump = ctx.bumps.decision_vote;
+    create_pda_account(
+        ctx.accounts.cranker.to_account_info(),
+        ctx.accounts.decision_vote.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ACCOUNT_DISCRIMINATOR + DecisionVote::INIT_SPACE,
+        &[DECISION_VOTE_SEED, quest_key_bytes.as_ref(), &[bump]],
+    )?;
+
+    let decision_vote = DecisionVote {
+        quest_key,
+        count_success: 0,
+        count_adjourn: 0,
+        total_voted: 0,
+        finalized: false,
+        bump,
+    };
+    {
+        let decision_vote_info = ctx.accounts.decision_vote.to_account_info();
+        let mut data = decision_vote_info.try_borrow_mut_data()?;
+        decision_vote.try_serialize(&mut &mut data[..])?;
+    }
+
+    let current_time = clock.unix_timestamp;
+    let end_time = current_time + (config.duration_hours as i64 * 3600);
+
+    governance_item.decision_start_time = current_time;
+    governance_item.decision_end_time = end_time;
+
+    emit!(DecisionStarted {
+        quest_key,
+        created_at: current_time as u64,
+        end_at: end_time as u64,
+    });
+
+    Ok(())
+}
+
+fn finalize_decision(ctx: &mut Context<AdvanceGovernanceItem>, quest_key: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp > governance_item.decision_end_time,
+        GovernanceError::VotingPeriodNotEnded
+    );
+
+    let decision_vote_info = ctx.accounts.decision_vote.to_account_info();
+    require!(decision_vote_info.owner == &crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let mut decision_vote = DecisionVote::try_deserialize(&mut &decision_vote_info.try_borrow_data()?[..])?;
+
+    let voting_supply = governance_item.voting_supply(&ctx.accounts.supply_checkpoints)?;
+    require_quorum(
+        config,
+        decision_vote.total_voted,
+        governance_item.decision_nft_turnout,
+        decision_vote.count_success + decision_vote.count_adjourn,
+        voting_supply,
+    )?;
+    require!(
+        decision_vote.count_success != decision_vote.count_adjourn,
+        GovernanceError::TiedVoteNeedsAuthority
+    );
+
+    let result = if tally_passes(
+        config.approval_bps,
+        decision_vote.count_success,
+        decision_vote.count_adjourn,
+        governance_item.decision_nft_turnout,
+        voting_supply,
+    ) {
+        DecisionResult::Success
+    } else {
+        DecisionResult::Adjourn
+    };
+
+    governance_item.decision_result = result.clone();
+    decision_vote.finalized = true;
+    {
+        let mut data = decision_vote_info.try_borrow_mut_data()?;
+        decision_vote.try_serialize(&mut &mut data[..])?;
+    }
+
+    if result == DecisionResult::Success {
+        require!(
+            !governance_item.answer_keys.is_empty(),
+            GovernanceError::AnswerOptionsNotSet
+        );
+
+        let answer_vote_info = ctx.accounts.answer_vote.to_account_info();
+        let bump = ctx.bumps.answer_vote;
+        let quest_key_bytes = quest_key.to_le_bytes();
+        create_pda_account(
+            ctx.accounts.cranker.to_account_info(),
+            answer_vote_info.clone(),
+            ctx.accounts.system_program.to_account_info(),
+            ACCOUNT_DISCRIMINATOR + AnswerVote::INIT_SPACE,
+            &[ANSWER_VOTE_SEED, quest_key_bytes.as_ref(), &[bump]],
+        )?;
+
+        let answer_vote = AnswerVote {
+            quest_key,
+            total_voted: 0,
+            finalized: false,
+            winning_answer: 0,
+            bump,
+        };
+        {
+            let mut data = answer_vote_info.try_borrow_mut_data()?;
+            answer_vote.try_serialize(&mut &mut data[..])?;
+        }
+
+        let current_time = clock.unix_timestamp;
+        let end_time = current_time + (config.duration_hours as i64 * 3600);
+
+        governance_item.answer_start_time = current_time;
+        governance_item.answer_end_time = end_time;
+
+        emit!(AnswerStarted {
+            quest_key,
+            created_at: current_time as u64,
+            end_at: end_time as u64,
+            answer_keys: governance_item.answer_keys.clone(),
+        });
+    }
+
+    emit!(DecisionResultSet {
+        quest_key,
+        result,
+        count_success: decision_vote.count_success,
+        count_adjourn: decision_vote.count_adjourn,
+        total_voted: decision_vote.total_voted,
+        decision_end_time: governance_item.decision_end_time,
+        finalized_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn finalize_answer(ctx: &mut Context<AdvanceGovernanceItem>) -> Result<()> {
+    let answer_vote_info = ctx.accounts.answer_vote.to_account_info();
+    require!(answer_vote_info.owner == &crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let mut answer_vote = AnswerVote::try_deserialize(&mut &answer_vote_info.try_borrow_data()?[..])?;
+    require!(!answer_vote.finalized, GovernanceError::NothingToAdvance);
+
+    // No authority pick here: a tie under AnswerTieBreak::Authority fails and waits for finalize_answer
+    finalize_answer_vote(
+        &ctx.accounts.config,
+        &mut ctx.accounts.governance_item,
+        &mut answer_vote,
+        &ctx.accounts.supply_checkpoints,
+        ctx.remaining_accounts,
+        None,
+        ctx.accounts.cranker.key(),
+    )?;
+
+    let mut data = answer_vote_info.try_borrow_mut_data()?;
+    answer_vote.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceItemAdvanced {
+    pub quest_key: u64,
+    pub transition: GovernanceTransition,
+    pub advanced_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::utils::create_pda_account;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, ANSWER_VOTE_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct ChangeAnswerVote<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    #[account(
+        mut,
+        seeds = [ANSWER_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = answer_vote.bump
+    )]
+    pub answer_vote: Account<'info, AnswerVote>,
+
+    #[account(
+        mut,
+        seeds = [b"answer_voter", quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = answer_voter_record.bump,
+        constraint = answer_voter_record.vote_count > 0 @ GovernanceError::NotVoted
+    )]
+    pub answer_voter_record: Account<'info, AnswerVoterRecord>,
+
+    /// Option the voter's votes are currently counted for
+    #[account(
+        mut,
+        seeds = [b"answer_option", quest_key.to_le_bytes().as_ref(), answer_voter_record.answer_key.to_le_bytes().as_ref()],
+        bump = old_answer_option.bump
+    )]
+    pub old_answer_option: Account<'info, AnswerOption>,
+
+    /// CHECK: Answer option PDA of the new answer key, created if nobody voted for it yet.
+    /// Omitted when revoking
+    #[account(mut)]
+    pub new_answer_option: Option<UncheckedAccount<'info>>,
+
+    /// Voter's checkpoint history, read for the staked NFTs behind the vote
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves the voter's answer votes to `new_answer_key` while the answer vote is open,
+/// or revokes them when it is None. A revoked voter may vote again with vote_answer.
+pub fn change_answer_vote(
+    ctx: Context<ChangeAnswerVote>,
+    quest_key: u64,
+    new_answer_key: Option<u64>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let answer_vote = &mut ctx.accounts.answer_vote;
+    let answer_voter_record = &mut ctx.accounts.answer_voter_record;
+    let old_answer_option = &mut ctx.accounts.old_answer_option;
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    require!(
+        !governance_item.is_commit_reveal(),
+        GovernanceError::CommitRevealRequired
+    );
+
+    require!(
+        !answer_vote.finalized,
+        GovernanceError::AnswerVoteFinalized
+    );
+
+    require!(
+        clock.unix_timestamp <= governance_item.answer_end_time,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    require!(
+        new_answer_key != Some(answer_voter_record.answer_key),
+        GovernanceError::SameVoteChoice
+    );
+
+    let votes = answer_voter_record.vote_count as u64;
+    let old_answer_key = answer_voter_record.answer_key;
+
+    old_answer_option.total_votes = old_answer_option
+        .total_votes
+        .checked_sub(votes)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    let mut new_answer_total_votes = 0;
+    match new_answer_key {
+        Some(answer_key) => {
+            require!(
+                governance_item.answer_keys.contains(&answer_key),
+                GovernanceError::InvalidAnswerKey
+            );
+
+            let option_info = ctx
+                .accounts
+                .new_answer_option
+                .as_ref()
+                .ok_or(GovernanceError::AnswerOptionMismatch)?
+                .to_account_info();
+            let quest_key_bytes = quest_key.to_le_bytes();
+            let answer_key_bytes = answer_key.to_le_bytes();
+            let (expected, bump) = Pubkey::find_program_address(
+                &[b"answer_option", quest_key_bytes.as_ref(), answer_key_bytes.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(option_info.key(), expected, GovernanceError::AnswerOptionMismatch);
+
+            let mut new_answer_option = if option_info.owner == &crate::ID {
+                AnswerOption::try_deserialize(&mut &option_info.try_borrow_data()?[..])?
+            } else {
+                create_pda_account(
+                    ctx.accounts.voter.to_account_info(),
+                    option_info.clone(),
+                    ctx.accounts.system_program.to_account_info(),
+                    ACCOUNT_DISCRIMINATOR + AnswerOption::INIT_SPACE,
+                    &[b"answer_option", quest_key_bytes.as_ref(), answer_key_bytes.as_ref(), &[bump]],
+                )?;
+                AnswerOption {
+                    quest_key,
+                    answer_key,
+                    total_votes: 0,
+                    is_active: true,
+                    bump,
+                }
+            };
+
+            new_answer_option.total_votes = new_answer_option
+                .total_votes
+                .checked_add(votes)
+                .ok_or(GovernanceError::MathOverflow)?;
+            new_answer_total_votes = new_answer_option.total_votes;
+            {
+                let mut data = option_info.try_borrow_mut_data()?;
+                new_answer_option.try_serialize(&mut &mut data[..])?;
+            }
+
+            answer_voter_record.answer_key = answer_key;
+        }
+        None => {
+            answer_vote.total_voted = answer_vote
+                .total_voted
+                .checked_sub(votes)
+                .ok_or(GovernanceError::MathOverflow)?;
+            answer_voter_record.vote_count = 0;
+            // Votes cast before answer_voter_count and the NFT turnout existed were never counted
+            governance_item.answer_voter_count = governance_item.answer_voter_count.saturating_sub(1);
+            let nft_count = ctx.accounts.voter_checkpoints.get_past_votes(governance_item.start_slot);
+            governance_item.answer_nft_turnout = governance_item.answer_nft_turnout.saturating_sub(nft_count as u64);
+        }
+    }
+
+    emit!(AnswerVoteChanged {
+        quest_key,
+        voter: ctx.accounts.voter.key(),
+        old_answer_key,
+        new_answer_key,
+        votes,
+        old_answer_total_votes: old_answer_option.total_votes,
+        new_answer_total_votes,
+        total_voted: answer_vote.total_voted,
+        voted_at: answer_voter_record.timestamp,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AnswerVoteChanged {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub old_answer_key: u64,
+    /// None when the vote was revoked
+    pub new_answer_key: Option<u64>,
+    /// Votes moved off `old_answer_key`
+    pub votes: u64,
+    pub old_answer_total_votes: u64,
+    /// 0 when the vote was revoked
+    pub new_answer_total_votes: u64,
+    pub total_voted: u64,
+    /// When the original vote was cast
+    pub voted_at: i64,
+    pub timestamp: i64,
+}
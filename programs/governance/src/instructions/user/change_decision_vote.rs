@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, DECISION_VOTE_SEED, DECISION_VOTER_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct ChangeDecisionVote<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    #[account(
+        mut,
+        seeds = [DECISION_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = decision_vote.bump
+    )]
+    pub decision_vote: Account<'info, DecisionVote>,
+
+    #[account(
+        mut,
+        seeds = [DECISION_VOTER_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+        constraint = voter_record.votes > 0 @ GovernanceError::NotVoted
+    )]
+    pub voter_record: Account<'info, DecisionVoterRecord>,
+
+    /// Voter's checkpoint history, read for the staked NFTs behind the vote
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    pub voter: Signer<'info>,
+}
+
+/// Moves the voter's decision votes to `new_choice` while the decision vote is open,
+/// or revokes them when `new_choice` is None. A revoked voter may vote again with vote_decision.
+pub fn change_decision_vote(
+    ctx: Context<ChangeDecisionVote>,
+    quest_key: u64,
+    new_choice: Option<DecisionVoteChoice>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let decision_vote = &mut ctx.accounts.decision_vote;
+    let voter_record = &mut ctx.accounts.voter_record;
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    require!(
+        governance_item.decision_result == DecisionResult::Pending && !decision_vote.finalized,
+        GovernanceError::DecisionAlreadyFinalized
+    );
+
+    require!(
+        clock.unix_timestamp <= governance_item.decision_end_time,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    require!(
+        new_choice.as_ref() != Some(&voter_record.vote_choice),
+        GovernanceError::SameVoteChoice
+    );
+
+    let votes = voter_record.votes;
+    let old_choice = voter_record.vote_choice.clone();
+
+    let old_count = match old_choice {
+        DecisionVoteChoice::Success => &mut decision_vote.count_success,
+        DecisionVoteChoice::Adjourn => &mut decision_vote.count_adjourn,
+    };
+    *old_count = old_count.checked_sub(votes).ok_or(GovernanceError::MathOverflow)?;
+
+    match &new_choice {
+        Some(choice) => {
+            let new_count = match choice {
+                DecisionVoteChoice::Success => &mut decision_vote.count_success,
+                DecisionVoteChoice::Adjourn => &mut decision_vote.count_adjourn,
+            };
+            *new_count = new_count.checked_add(votes).ok_or(GovernanceError::MathOverflow)?;
+            voter_record.vote_choice = choice.clone();
+        }
+        None => {
+            decision_vote.total_voted = decision_vote
+                .total_voted
+                .checked_sub(1)
+                .ok_or(GovernanceError::MathOverflow)?;
+            voter_record.votes = 0;
+            // Saturating: votes cast before the NFT turnout was tracked are not in it
+            let nft_count = ctx.accounts.voter_checkpoints.get_past_votes(governance_item.start_slot);
+            governance_item.decision_nft_turnout = governance_item.decision_nft_turnout.saturating_sub(nft_count as u64);
+        }
+    }
+
+    emit!(DecisionVoteChanged {
+        quest_key,
+        voter: ctx.accounts.voter.key(),
+        old_choice,
+        new_choice,
+        votes,
+        count_success: decision_vote.count_success,
+        count_adjourn: decision_vote.count_adjourn,
+        total_voted: decision_vote.total_voted,
+        voted_at: voter_record.timestamp,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DecisionVoteChanged {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub old_choice: DecisionVoteChoice,
+    /// None when the vote was revoked
+    pub new_choice: Option<DecisionVoteChoice>,
+    /// Votes moved off `old_choice`
+    pub votes: u64,
+    pub count_success: u64,
+    pub count_adjourn: u64,
+    pub total_voted: u64,
+    /// When the original vote was cast
+    pub voted_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED, QUEST_VOTER_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct ChangeQuestVote<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    #[account(
+        mut,
+        seeds = [QUEST_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = quest_vote.bump
+    )]
+    pub quest_vote: Account<'info, QuestVote>,
+
+    #[account(
+        mut,
+        seeds = [QUEST_VOTER_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+        constraint = voter_record.vote_count > 0 @ GovernanceError::NotVoted
+    )]
+    pub voter_record: Account<'info, QuestVoterRecord>,
+
+    /// Voter's checkpoint history, read for the staked NFTs behind the vote
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    pub voter: Signer<'info>,
+}
+
+/// Moves the voter's counted quest votes to `new_choice` while the quest vote is open,
+/// or revokes them when `new_choice` is None. The counted weight is not re-read from
+/// checkpoints (only the NFT turnout a revoke gives back is), and a revoked voter may vote
+/// again with vote_quest.
+pub fn change_quest_vote(
+    ctx: Context<ChangeQuestVote>,
+    quest_key: u64,
+    new_choice: Option<QuestVoteChoice>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let quest_vote = &mut ctx.accounts.quest_vote;
+    let voter_record = &mut ctx.accounts.voter_record;
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    require!(
+        governance_item.quest_result == QuestResult::Pending && !quest_vote.finalized,
+        GovernanceError::QuestAlreadyFinalized
+    );
+
+    require!(
+        clock.unix_timestamp <= governance_item.quest_end_time,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    require!(
+        new_choice.as_ref() != Some(&voter_record.vote_choice),
+        GovernanceError::SameVoteChoice
+    );
+
+    let votes = voter_record.vote_count as u64;
+    let old_choice = voter_record.vote_choice.clone();
+
+    let old_count = match old_choice {
+        QuestVoteChoice::Approve => &mut quest_vote.count_approver,
+        QuestVoteChoice::Reject => &mut quest_vote.count_rejector,
+    };
+    *old_count = old_count.checked_sub(votes).ok_or(GovernanceError::MathOverflow)?;
+
+    match &new_choice {
+        Some(choice) => {
+            let new_count = match choice {
+                QuestVoteChoice::Approve => &mut quest_vote.count_approver,
+                QuestVoteChoice::Reject => &mut quest_vote.count_rejector,
+            };
+            *new_count = new_count.checked_add(votes).ok_or(GovernanceError::MathOverflow)?;
+            voter_record.vote_choice = choice.clone();
+        }
+        None => {
+            quest_vote.total_voted = quest_vote
+                .total_voted
+                .checked_sub(1)
+                .ok_or(GovernanceError::MathOverflow)?;
+            voter_record.vote_count = 0;
+            // Saturating: votes cast before the NFT turnout was tracked are not in it
+            let nft_count = ctx.accounts.voter_checkpoints.get_past_votes(governance_item.start_slot);
+            governance_item.quest_nft_turnout = governance_item.quest_nft_turnout.saturating_sub(nft_count as u64);
+        }
+    }
+
+    emit!(QuestVoteChanged {
+        quest_key,
+        voter: ctx.accounts.voter.key(),
+        old_choice,
+        new_choice,
+        votes,
+        count_approver: quest_vote.count_approver,
+        count_rejector: quest_vote.count_rejector,
+        total_voted: quest_vote.total_voted,
+        voted_at: voter_record.timestamp,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct QuestVoteChanged {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub old_choice: QuestVoteChoice,
+    /// None when the vote was revoked
+    pub new_choice: Option<QuestVoteChoice>,
+    /// Votes moved off `old_choice`
+    pub votes: u64,
+    pub count_approver: u64,
+    pub count_rejector: u64,
+    pub total_voted: u64,
+    /// When the original vote was cast
+    pub voted_at: i64,
+    pub timestamp: i64,
+}
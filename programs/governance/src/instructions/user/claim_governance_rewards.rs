@@ -0,0 +1,295 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::utils::{pay_answer_reward, transfer_from_treasury};
+use crate::constant::{
+    ACCOUNT_DISCRIMINATOR, ANSWER_VOTE_SEED, DECISION_VOTER_SEED, DECISION_VOTE_SEED,
+    GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, GOVERNANCE_SEED, PHASE_REWARD_CLAIM_SEED,
+    QUEST_VOTER_SEED, QUEST_VOTE_SEED, REWARD_POOL_SEED, TREASURY_SEED, TREASURY_TOKEN_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct ClaimGovernanceRewards<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, GovernanceConfig>>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Box<Account<'info, Governance>>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Box<Account<'info, GovernanceItem>>,
+
+    #[account(
+        seeds = [QUEST_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = quest_vote.bump
+    )]
+    pub quest_vote: Box<Account<'info, QuestVote>>,
+
+    /// Pass to claim the quest-phase reward
+    #[account(
+        seeds = [QUEST_VOTER_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = quest_voter_record.bump
+    )]
+    pub quest_voter_record: Option<Box<Account<'info, QuestVoterRecord>>>,
+
+    #[account(
+        seeds = [DECISION_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = decision_vote.bump
+    )]
+    pub decision_vote: Option<Box<Account<'info, DecisionVote>>>,
+
+    /// Pass with decision_vote to claim the decision-phase reward
+    #[account(
+        seeds = [DECISION_VOTER_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = decision_voter_record.bump
+    )]
+    pub decision_voter_record: Option<Box<Account<'info, DecisionVoterRecord>>>,
+
+    /// Quest- and decision-phase rewards already claimed, created on the first claim
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + PhaseRewardClaim::INIT_SPACE,
+        seeds = [PHASE_REWARD_CLAIM_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Box<Account<'info, PhaseRewardClaim>>,
+
+    #[account(
+        seeds = [ANSWER_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = answer_vote.bump
+    )]
+    pub answer_vote: Option<Box<Account<'info, AnswerVote>>>,
+
+    /// Pass with answer_vote to claim the answer-phase reward
+    #[account(
+        mut,
+        seeds = [b"answer_voter", quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = answer_voter_record.bump
+    )]
+    pub answer_voter_record: Option<Box<Account<'info, AnswerVoterRecord>>>,
+
+    /// Tally of the winning answer, required for the answer reward of a quest with a reward pool
+    #[account(
+        seeds = [
+            b"answer_option",
+            quest_key.to_le_bytes().as_ref(),
+            governance_item.answer_result.to_le_bytes().as_ref()
+        ],
+        bump = winning_answer_option.bump
+    )]
+    pub winning_answer_option: Option<Box<Account<'info, AnswerOption>>>,
+
+    /// Quest's reward pool, required for the answer reward of a quest with a reward pool
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, quest_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ GovernanceError::Unauthorized,
+        constraint = voter_token_account.mint == config.base_token_mint @ GovernanceError::InvalidParameter
+    )]
+    pub voter_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA signer derived from program seeds
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump
+    )]
+    pub treasury_pda: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, TREASURY_TOKEN_ACCOUNT_SEED],
+        bump,
+        constraint = treasury_token_account.owner == treasury_pda.key() @ GovernanceError::InvalidParameter,
+        constraint = treasury_token_account.mint == config.base_token_mint @ GovernanceError::InvalidParameter
+    )]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays every reward the voter has earned on a quest in one call:
+/// - quest phase: `quest_reward_token` per vote for siding with the majority that decided the result
+/// - decision phase: `decision_reward_token` per vote, likewise
+/// - answer phase: the same payout as `distribute_dao_reward` for picking the winning answer
+///
+/// Phases whose records are not passed, are not finalized or were not won are skipped
+/// and stay claimable later; each phase is paid at most once, as recorded in the
+/// voter's PhaseRewardClaim and, for the answer phase, the answer voter record.
+pub fn claim_governance_rewards(ctx: Context<ClaimGovernanceRewards>, quest_key: u64) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GovernanceError::GovernancePaused);
+
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let quest_vote = &ctx.accounts.quest_vote;
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    reward_claim.quest_key = quest_key;
+    reward_claim.voter = ctx.accounts.voter.key();
+    reward_claim.bump = ctx.bumps.reward_claim;
+
+    let mut quest_reward = 0;
+    if let Some(record) = ctx.accounts.quest_voter_record.as_ref() {
+        let result_choice = match governance_item.quest_result {
+            QuestResult::Approved => Some(QuestVoteChoice::Approve),
+            QuestResult::Rejected => Some(QuestVoteChoice::Reject),
+            QuestResult::Pending => None,
+        };
+        if quest_vote.finalized
+            && config.quest_reward_token > 0
+            && record.vote_count > 0
+            && !reward_claim.quest_rewarded
+            && result_choice.is_some()
+            && quest_vote.majority() == result_choice
+            && Some(&record.vote_choice) == result_choice.as_ref()
+        {
+            quest_reward = (record.vote_count as u64)
+                .checked_mul(config.quest_reward_token)
+                .ok_or(GovernanceError::MathOverflow)?;
+            reward_claim.quest_rewarded = true;
+        }
+    }
+
+    let mut decision_reward = 0;
+    if let (Some(decision_vote), Some(record)) = (
+        ctx.accounts.decision_vote.as_ref(),
+        ctx.accounts.decision_voter_record.as_ref(),
+    ) {
+        let result_choice = match governance_item.decision_result {
+            DecisionResult::Success => Some(DecisionVoteChoice::Success),
+            DecisionResult::Adjourn => Some(DecisionVoteChoice::Adjourn),
+            DecisionResult::Pending => None,
+        };
+        if decision_vote.finalized
+            && config.decision_reward_token > 0
+            && record.votes > 0
+            && !reward_claim.decision_rewarded
+            && result_choice.is_some()
+            && decision_vote.majority() == result_choice
+            && Some(&record.vote_choice) == result_choice.as_ref()
+        {
+            decision_reward = record
+                .votes
+                .checked_mul(config.decision_reward_token)
+                .ok_or(GovernanceError::MathOverflow)?;
+            reward_claim.decision_rewarded = true;
+        }
+    }
+
+    let mut answer_reward = 0;
+    let mut answer_key = 0;
+    let mut answer_from_pool = false;
+    if let (Some(answer_vote), Some(record)) = (
+        ctx.accounts.answer_vote.as_ref(),
+        ctx.accounts.answer_voter_record.as_mut(),
+    ) {
+        if answer_vote.finalized
+            && governance_item.answer_result != 0
+            && record.answer_key == governance_item.answer_result
+            && record.vote_count > 0
+            && !record.rewarded
+        {
+            (answer_reward, answer_from_pool) = pay_answer_reward(
+                config,
+                governance_item,
+                record,
+                ctx.accounts.reward_pool_token_account.as_deref().map(|account| &**account),
+                ctx.accounts.winning_answer_option.as_deref().map(|account| &**account),
+            )?;
+            answer_key = record.answer_key;
+        }
+    }
+
+    let pool_reward = if answer_from_pool { answer_reward } else { 0 };
+    let treasury_reward = quest_reward
+        .checked_add(decision_reward)
+        .and_then(|sum| sum.checked_add(answer_reward - pool_reward))
+        .ok_or(GovernanceError::MathOverflow)?;
+    let total_reward = treasury_reward
+        .checked_add(pool_reward)
+        .ok_or(GovernanceError::MathOverflow)?;
+    require!(total_reward > 0, GovernanceError::NothingToClaim);
+
+    let treasury_bump = config.treasury_bump;
+    if treasury_reward > 0 {
+        require!(
+            ctx.accounts.treasury_token_account.amount >= treasury_reward,
+            GovernanceError::InsufficientTreasuryBalance
+        );
+        transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.treasury_token_account.to_account_info(),
+            ctx.accounts.voter_token_account.to_account_info(),
+            ctx.accounts.treasury_pda.to_account_info(),
+            treasury_bump,
+            treasury_reward,
+        )?;
+    }
+    if pool_reward > 0 {
+        let Some(reward_pool_token_account) = ctx.accounts.reward_pool_token_account.as_ref() else {
+            return err!(GovernanceError::RewardPoolAccountsRequired);
+        };
+        transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            reward_pool_token_account.to_account_info(),
+            ctx.accounts.voter_token_account.to_account_info(),
+            ctx.accounts.treasury_pda.to_account_info(),
+            treasury_bump,
+            pool_reward,
+        )?;
+    }
+
+    let governance = &mut ctx.accounts.governance;
+    governance.total_rewards_distributed = governance
+        .total_rewards_distributed
+        .checked_add(total_reward)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    emit!(GovernanceRewardsClaimed {
+        quest_key,
+        voter: ctx.accounts.voter.key(),
+        quest_reward,
+        decision_reward,
+        answer_key,
+        answer_reward,
+        total_rewards_distributed: governance.total_rewards_distributed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceRewardsClaimed {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub quest_reward: u64,
+    pub decision_reward: u64,
+    /// Winning answer the answer reward was paid for, 0 without one
+    pub answer_key: u64,
+    pub answer_reward: u64,
+    pub total_rewards_distributed: u64,
+    pub timestamp: i64,
+}
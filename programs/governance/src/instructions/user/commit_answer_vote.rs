@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, ANSWER_COMMIT_SEED, ANSWER_VOTE_SEED, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, VOTER_STAKE_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct CommitAnswerVote<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    #[account(
+        seeds = [ANSWER_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = answer_vote.bump
+    )]
+    pub answer_vote: Account<'info, AnswerVote>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + AnswerCommit::INIT_SPACE,
+        seeds = [ANSWER_COMMIT_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub answer_commit: Account<'info, AnswerCommit>,
+
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    /// Voter's NFT escrow; committing locks it until the reveal window closes
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + VoterStake::INIT_SPACE,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stores `commitment` (see `answer_commitment`) as the voter's hidden answer vote.
+/// Committing again during the answer window replaces the commitment.
+pub fn commit_answer_vote(
+    ctx: Context<CommitAnswerVote>,
+    quest_key: u64,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &ctx.accounts.governance_item;
+    let answer_commit = &mut ctx.accounts.answer_commit;
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    require!(
+        governance_item.is_commit_reveal(),
+        GovernanceError::CommitRevealNotEnabled
+    );
+
+    require!(
+        !governance_item.answer_keys.is_empty() && governance_item.answer_start_time != 0,
+        GovernanceError::AnswerVotingNotStarted
+    );
+
+    require!(
+        clock.unix_timestamp <= governance_item.answer_end_time,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    require!(
+        !ctx.accounts.answer_vote.finalized,
+        GovernanceError::AnswerVoteFinalized
+    );
+
+    let voting_power = ctx.accounts.voter_checkpoints.get_past_votes(governance_item.start_slot);
+    require!(
+        voting_power >= config.min_required_nft,
+        GovernanceError::InsufficientNfts
+    );
+    let vote_count = config.vote_weighting.weight(voting_power, config.max_votable_nft);
+
+    answer_commit.quest_key = quest_key;
+    answer_commit.voter = ctx.accounts.voter.key();
+    answer_commit.commitment = commitment;
+    answer_commit.vote_count = vote_count;
+    answer_commit.revealed = false;
+    answer_commit.timestamp = clock.unix_timestamp;
+    answer_commit.bump = ctx.bumps.answer_commit;
+
+    voter_stake.init_if_needed(ctx.accounts.voter.key(), ctx.bumps.voter_stake);
+    voter_stake.lock_until(governance_item.answer_close_time());
+
+    emit!(AnswerVoteCommitted {
+        quest_key,
+        voter: ctx.accounts.voter.key(),
+        commitment,
+        votes: vote_count as u64,
+        snapshot_slot: governance_item.start_slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AnswerVoteCommitted {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub commitment: [u8; 32],
+    pub votes: u64,
+    pub snapshot_slot: u64,
+    pub timestamp: i64,
+}
@@ -3,7 +3,10 @@ use anchor_spl::token::TokenAccount;
 use mpl_token_metadata::accounts::Metadata as MetaplexMetadata;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED};
+use crate::constant::{
+    ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, GOVERNANCE_ITEM_VERSION, QUEST_VOTE_SEED,
+    SUPPLY_CHECKPOINTS_SEED, VOTER_STAKE_SEED,
+};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -43,6 +46,23 @@ pub struct CreateGovernanceItem<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    /// Creator's NFT escrow; staked NFTs count towards the minimum alongside held ones
+    #[account(
+        seeds = [VOTER_STAKE_SEED, creator.key().as_ref()],
+        bump = creator_stake.bump
+    )]
+    pub creator_stake: Option<Account<'info, VoterStake>>,
+
+    /// Global supply history; the current staked supply is recorded on the item
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = ACCOUNT_DISCRIMINATOR + SupplyCheckpoints::INIT_SPACE,
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
     /// Remaining accounts should be pairs of [NFT token account, NFT metadata account] from the governance collection
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
@@ -138,6 +158,10 @@ pub fn create_governance(
         valid_nft_count += 1;
     }
 
+    if let Some(creator_stake) = &ctx.accounts.creator_stake {
+        valid_nft_count = valid_nft_count.saturating_add(creator_stake.staked_count);
+    }
+
     require!(
         valid_nft_count >= min_required_nft,
         GovernanceError::InsufficientNfts
@@ -161,6 +185,13 @@ pub fn create_governance(
     governance_item.answer_end_time = 0;
     governance_item.answer_keys = Vec::new();
     governance_item.bump = ctx.bumps.governance_item;
+    governance_item.version = GOVERNANCE_ITEM_VERSION;
+
+    let supply_checkpoints = &mut ctx.accounts.supply_checkpoints;
+    if supply_checkpoints.checkpoints.is_empty() {
+        supply_checkpoints.bump = ctx.bumps.supply_checkpoints;
+    }
+    governance_item.voting_supply = supply_checkpoints.staked();
     
     quest_vote.quest_key = quest_key;
     quest_vote.count_approver = 0;
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, DELEGATE_INDEX_SEED, GOVERNANCE_CONFIG_SEED, MAX_DELEGATORS, VOTER_STAKE_SEED};
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, delegator.key().as_ref()],
+        bump = delegator_stake.bump,
+        constraint = delegator_stake.staked_count > 0 @ GovernanceError::NoStakedNfts,
+        constraint = !delegator_stake.is_delegating() @ GovernanceError::VotesDelegated
+    )]
+    pub delegator_stake: Account<'info, VoterStake>,
+
+    #[account(
+        mut,
+        seeds = [b"voter_checkpoints", delegator.key().as_ref()],
+        bump = delegator_checkpoints.bump
+    )]
+    pub delegator_checkpoints: Account<'info, VoterCheckpoints>,
+
+    /// Co-signs to accept the votes, so no wallet gets checkpoints written without consent
+    #[account(
+        constraint = delegate.key() != delegator.key() @ GovernanceError::InvalidDelegate
+    )]
+    pub delegate: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = ACCOUNT_DISCRIMINATOR + VoterStake::INIT_SPACE,
+        seeds = [VOTER_STAKE_SEED, delegate.key().as_ref()],
+        bump
+    )]
+    pub delegate_stake: Account<'info, VoterStake>,
+
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = ACCOUNT_DISCRIMINATOR + VoterCheckpoints::INIT_SPACE,
+        seeds = [b"voter_checkpoints", delegate.key().as_ref()],
+        bump
+    )]
+    pub delegate_checkpoints: Account<'info, VoterCheckpoints>,
+
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = ACCOUNT_DISCRIMINATOR + DelegateIndex::INIT_SPACE,
+        seeds = [DELEGATE_INDEX_SEED, delegate.key().as_ref()],
+        bump
+    )]
+    pub delegate_index: Account<'info, DelegateIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves the delegator's staked voting power to `delegate`, checkpointing both at the current slot.
+/// Snapshots taken before this slot keep counting the power for the delegator.
+/// The delegate signs too; undelegating only needs the delegator.
+pub fn delegate_votes(ctx: Context<DelegateVotes>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let delegator_key = ctx.accounts.delegator.key();
+    let delegate_key = ctx.accounts.delegate.key();
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    let delegate_index = &mut ctx.accounts.delegate_index;
+    if delegate_index.delegate == Pubkey::default() {
+        delegate_index.delegate = delegate_key;
+        delegate_index.delegators = Vec::new();
+        delegate_index.bump = ctx.bumps.delegate_index;
+    }
+    require!(
+        delegate_index.delegators.len() < MAX_DELEGATORS,
+        GovernanceError::TooManyDelegators
+    );
+
+    let delegator_stake = &mut ctx.accounts.delegator_stake;
+    let votes = delegator_stake.staked_count;
+    delegator_stake.delegate = delegate_key;
+
+    delegate_index.delegators.push(DelegatorEntry {
+        delegator: delegator_key,
+        votes,
+    });
+
+    let delegate_stake = &mut ctx.accounts.delegate_stake;
+    delegate_stake.init_if_needed(delegate_key, ctx.bumps.delegate_stake);
+    delegate_stake.delegated_votes = delegate_stake
+        .delegated_votes
+        .checked_add(votes)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    let delegate_checkpoints = &mut ctx.accounts.delegate_checkpoints;
+    if delegate_checkpoints.voter == Pubkey::default() {
+        delegate_checkpoints.voter = delegate_key;
+        delegate_checkpoints.checkpoints = Vec::new();
+        delegate_checkpoints.bump = ctx.bumps.delegate_checkpoints;
+    }
+
+    let delegator_votes = delegator_stake.voting_power()?;
+    let delegate_votes = delegate_stake.voting_power()?;
+    ctx.accounts.delegator_checkpoints.update_checkpoint(clock.slot, delegator_votes)?;
+    delegate_checkpoints.update_checkpoint(clock.slot, delegate_votes)?;
+
+    emit!(VotesDelegated {
+        delegator: delegator_key,
+        delegate: delegate_key,
+        votes,
+        delegator_votes,
+        delegate_votes,
+        delegator_count: delegate_index.delegators.len() as u8,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VotesDelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    /// Staked NFTs moved to the delegate
+    pub votes: u8,
+    /// Checkpointed voting power of each side after the move
+    pub delegator_votes: u8,
+    pub delegate_votes: u8,
+    pub delegator_count: u8,
+    pub slot: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, REWARD_POOL_SEED, TREASURY_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct FundRewardPool<'info> {
+    /// The item's creator or the governance authority
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump,
+        constraint = governance_item.creator == funder.key()
+            || config.authority == funder.key() @ GovernanceError::Unauthorized
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == funder.key() @ GovernanceError::Unauthorized,
+        constraint = funder_token_account.mint == config.base_token_mint @ GovernanceError::InvalidParameter
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    #[account(address = config.base_token_mint @ GovernanceError::InvalidParameter)]
+    pub base_token_mint: Account<'info, Mint>,
+
+    /// CHECK: PDA derived from TREASURY_SEED, authority of the pool token account
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump
+    )]
+    pub treasury_pda: AccountInfo<'info>,
+
+    /// Quest's reward pool, created on first funding
+    #[account(
+        init_if_needed,
+        payer = funder,
+        token::mint = base_token_mint,
+        token::authority = treasury_pda,
+        seeds = [REWARD_POOL_SEED, quest_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_pool_token_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Adds tokens to a quest's reward pool. Once the pool reaches the config's min_reward_pool,
+/// the quest's answer rewards are paid as a pro-rata share of the pool instead of the
+/// constant per-vote reward. Funding closes when the answer is set or the item is cancelled.
+pub fn fund_reward_pool(ctx: Context<FundRewardPool>, quest_key: u64, amount: u64) -> Result<()> {
+    require!(!ctx.accounts.config.paused, GovernanceError::GovernancePaused);
+    require!(amount > 0, GovernanceError::InvalidParameter);
+
+    let governance_item = &mut ctx.accounts.governance_item;
+    require!(
+        governance_item.quest_result != QuestResult::Rejected
+            && governance_item.decision_result != DecisionResult::Adjourn
+            && governance_item.answer_result == 0,
+        GovernanceError::RewardPoolClosed
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.reward_pool_token_account.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    governance_item.reward_pool_amount = governance_item
+        .reward_pool_amount
+        .checked_add(amount)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    emit!(RewardPoolFunded {
+        quest_key,
+        funder: ctx.accounts.funder.key(),
+        amount,
+        reward_pool_amount: governance_item.reward_pool_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RewardPoolFunded {
+    pub quest_key: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub reward_pool_amount: u64,
+    pub timestamp: i64,
+}
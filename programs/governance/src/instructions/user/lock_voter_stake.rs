@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constant::VOTER_STAKE_SEED;
+
+#[derive(Accounts)]
+pub struct LockVoterStake<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump = voter_stake.bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+}
+
+/// Extends the voter's unstake lock to `locked_until`; an earlier time leaves it unchanged.
+/// Programs that grant benefits for staked NFTs call it through CPI, with the voter's
+/// signature, so the NFTs stay put for as long as the benefit depends on them.
+pub fn lock_voter_stake(ctx: Context<LockVoterStake>, locked_until: i64) -> Result<()> {
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.lock_until(locked_until);
+
+    emit!(VoterStakeLocked {
+        voter: ctx.accounts.voter.key(),
+        staked_count: voter_stake.staked_count,
+        locked_until: voter_stake.locked_until,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoterStakeLocked {
+    pub voter: Pubkey,
+    pub staked_count: u8,
+    pub locked_until: i64,
+    pub timestamp: i64,
+}
@@ -11,7 +11,7 @@ use anchor_spl::{
 };
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::GOVERNANCE_CONFIG_SEED;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, SUPPLY_CHECKPOINTS_SEED};
 
 #[derive(Accounts)]
 pub struct MintGovernanceNft<'info> {
@@ -128,6 +128,16 @@ pub struct MintGovernanceNft<'info> {
     /// CHECK: The user receiving the NFT
     pub receiver: UncheckedAccount<'info>,
 
+    /// Global supply history, checkpointed with every mint and stake
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ACCOUNT_DISCRIMINATOR + SupplyCheckpoints::INIT_SPACE,
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -221,6 +231,12 @@ pub fn mint_governance_nft(
 
     governance.total_nfts_minted += 1;
 
+    let supply_checkpoints = &mut ctx.accounts.supply_checkpoints;
+    if supply_checkpoints.checkpoints.is_empty() {
+        supply_checkpoints.bump = ctx.bumps.supply_checkpoints;
+    }
+    supply_checkpoints.record_minted(Clock::get()?.slot, governance.total_nfts_minted);
+
     // Verify the NFT as part of the collection
     let collection_authority_bump = ctx.bumps.collection_authority;
     let collection_authority_seeds = &[b"collection-authority".as_ref(), &[collection_authority_bump]];
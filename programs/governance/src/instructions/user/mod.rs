@@ -1,17 +1,49 @@
+pub mod advance_governance_item;
+pub mod change_answer_vote;
+pub mod change_decision_vote;
+pub mod change_quest_vote;
+pub mod claim_governance_rewards;
+pub mod commit_answer_vote;
 pub mod create_governance;
 pub mod create_proposal;
+pub mod delegate_votes;
+pub mod fund_reward_pool;
+pub mod lock_voter_stake;
 pub mod mint_governance_nft;
+pub mod refund_reward_pool;
+pub mod reveal_answer_vote;
+pub mod set_answer_options;
+pub mod stake_governance_nft;
 pub mod start_decision;
+pub mod undelegate_votes;
+pub mod unstake_governance_nft;
 pub mod vote_answer;
 pub mod vote_decision;
+pub mod vote_proposal;
 pub mod vote_quest;
 pub mod update_voter_checkpoint;
 
+pub use advance_governance_item::*;
+pub use change_answer_vote::*;
+pub use change_decision_vote::*;
+pub use change_quest_vote::*;
+pub use claim_governance_rewards::*;
+pub use commit_answer_vote::*;
 pub use create_governance::*;
 pub use create_proposal::*;
+pub use delegate_votes::*;
+pub use fund_reward_pool::*;
+pub use lock_voter_stake::*;
 pub use mint_governance_nft::*;
+pub use refund_reward_pool::*;
+pub use reveal_answer_vote::*;
+pub use set_answer_options::*;
+pub use stake_governance_nft::*;
 pub use start_decision::*;
+pub use undelegate_votes::*;
+pub use unstake_governance_nft::*;
 pub use vote_answer::*;
 pub use vote_decision::*;
+pub use vote_proposal::*;
 pub use vote_quest::*;
 pub use update_voter_checkpoint::*;
\ No newline at end of file
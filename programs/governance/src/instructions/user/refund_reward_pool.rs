@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{
+    ANSWER_VOTE_SEED, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, REWARD_POOL_SEED, TREASURY_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct RefundRewardPool<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    /// Required when the item was cancelled in the answer phase
+    #[account(
+        seeds = [ANSWER_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = answer_vote.bump
+    )]
+    pub answer_vote: Option<Account<'info, AnswerVote>>,
+
+    /// CHECK: PDA derived from TREASURY_SEED, authority of the pool token account
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump
+    )]
+    pub treasury_pda: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, quest_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_pool_token_account: Account<'info, TokenAccount>,
+
+    /// Treasury token account receiving the pool balance
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == treasury_pda.key() @ GovernanceError::InvalidParameter,
+        constraint = treasury_token_account.mint == reward_pool_token_account.mint @ GovernanceError::InvalidParameter
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Returns the balance of an unused reward pool to the treasury. Anyone may call it once the
+/// quest was rejected, the decision adjourned or the answer cancelled, or once the answer is
+/// finalized on a quest whose pool was below min_reward_pool then and paid the constant reward.
+pub fn refund_reward_pool(ctx: Context<RefundRewardPool>, quest_key: u64) -> Result<()> {
+    let governance_item = &ctx.accounts.governance_item;
+
+    let answer_cancelled = ctx
+        .accounts
+        .answer_vote
+        .as_ref()
+        .is_some_and(|answer_vote| answer_vote.finalized && answer_vote.winning_answer == 0);
+    require!(
+        governance_item.quest_result == QuestResult::Rejected
+            || governance_item.decision_result == DecisionResult::Adjourn
+            || answer_cancelled
+            || (governance_item.answer_result != 0 && !governance_item.pays_reward_pool),
+        GovernanceError::RewardPoolNotRefundable
+    );
+
+    let amount = ctx.accounts.reward_pool_token_account.amount;
+    require!(amount > 0, GovernanceError::RewardPoolEmpty);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.reward_pool_token_account.to_account_info(),
+        to: ctx.accounts.treasury_token_account.to_account_info(),
+        authority: ctx.accounts.treasury_pda.to_account_info(),
+    };
+    let treasury_bump = ctx.accounts.config.treasury_bump;
+    let signer_seeds: [&[u8]; 2] = [TREASURY_SEED, &[treasury_bump]];
+    let signer_seeds_refs: [&[&[u8]]; 1] = [&signer_seeds];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        &signer_seeds_refs,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(RewardPoolRefunded {
+        quest_key,
+        amount,
+        refunded_by: ctx.accounts.cranker.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RewardPoolRefunded {
+    pub quest_key: u64,
+    pub amount: u64,
+    pub refunded_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, ANSWER_COMMIT_SEED, ANSWER_VOTE_SEED, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED};
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64, answer_key: u64)]
+pub struct RevealAnswerVote<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    #[account(
+        mut,
+        seeds = [ANSWER_VOTE_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = answer_vote.bump
+    )]
+    pub answer_vote: Account<'info, AnswerVote>,
+
+    #[account(
+        mut,
+        seeds = [ANSWER_COMMIT_SEED, quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = answer_commit.bump
+    )]
+    pub answer_commit: Account<'info, AnswerCommit>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + AnswerOption::INIT_SPACE,
+        seeds = [b"answer_option", quest_key.to_le_bytes().as_ref(), answer_key.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub answer_option: Account<'info, AnswerOption>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + AnswerVoterRecord::INIT_SPACE,
+        seeds = [b"answer_voter", quest_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub answer_voter_record: Account<'info, AnswerVoterRecord>,
+
+    /// Voter's checkpoint history, read for the staked NFTs behind the vote
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the voter's commitment during the reveal window and counts it like vote_answer,
+/// with the weight snapshotted at commit time. Commits left unrevealed never get an
+/// AnswerVoterRecord, so they are not counted and forfeit distribute_dao_reward.
+pub fn reveal_answer_vote(
+    ctx: Context<RevealAnswerVote>,
+    quest_key: u64,
+    answer_key: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance_item = &mut ctx.accounts.governance_item;
+    let answer_vote = &mut ctx.accounts.answer_vote;
+    let answer_commit = &mut ctx.accounts.answer_commit;
+    let answer_option = &mut ctx.accounts.answer_option;
+    let answer_voter_record = &mut ctx.accounts.answer_voter_record;
+    let voter = ctx.accounts.voter.key();
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    require!(
+        clock.unix_timestamp > governance_item.answer_end_time
+            && clock.unix_timestamp <= governance_item.answer_close_time(),
+        GovernanceError::RevealWindowClosed
+    );
+
+    require!(!answer_vote.finalized, GovernanceError::AnswerVoteFinalized);
+    require!(!answer_commit.revealed, GovernanceError::AlreadyRevealed);
+
+    require!(
+        answer_commitment(quest_key, &voter, answer_key, &salt) == answer_commit.commitment,
+        GovernanceError::CommitmentMismatch
+    );
+
+    require!(
+        governance_item.answer_keys.contains(&answer_key),
+        GovernanceError::InvalidAnswerKey
+    );
+
+    require!(
+        answer_voter_record.quest_key == 0,
+        GovernanceError::AlreadyVoted
+    );
+
+    if answer_option.quest_key == 0 {
+        answer_option.quest_key = quest_key;
+        answer_option.answer_key = answer_key;
+        answer_option.total_votes = 0;
+        answer_option.is_active = true;
+        answer_option.bump = ctx.bumps.answer_option;
+    }
+
+    let votes = answer_commit.vote_count;
+    answer_commit.revealed = true;
+
+    answer_voter_record.quest_key = quest_key;
+    answer_voter_record.voter = voter;
+    answer_voter_record.answer_key = answer_key;
+    answer_voter_record.vote_count = votes;
+    answer_voter_record.timestamp = answer_commit.timestamp;
+    answer_voter_record.rewarded = false;
+    answer_voter_record.bump = ctx.bumps.answer_voter_record;
+
+    answer_option.total_votes = answer_option
+        .total_votes
+        .checked_add(votes as u64)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    answer_vote.total_voted = answer_vote
+        .total_voted
+        .checked_add(votes as u64)
+        .ok_or(GovernanceError::MathOverflow)?;
+    governance_item.add_answer_voter()?;
+    let nft_count = ctx.accounts.voter_checkpoints.get_past_votes(governance_item.start_slot);
+    governance_item.answer_nft_turnout = governance_item
+        .answer_nft_turnout
+        .checked_add(nft_count as u64)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    emit!(AnswerVoteRevealed {
+        quest_key,
+        answer_key,
+        voter,
+        votes: votes as u64,
+        answer_total_votes: answer_option.total_votes,
+        total_voted: answer_vote.total_voted,
+        committed_at: answer_commit.timestamp,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AnswerVoteRevealed {
+    pub quest_key: u64,
+    pub answer_key: u64,
+    pub voter: Pubkey,
+    pub votes: u64,
+    pub answer_total_votes: u64,
+    pub total_voted: u64,
+    pub committed_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::utils::validate_answer_keys;
+use crate::constant::GOVERNANCE_ITEM_SEED;
+
+#[derive(Accounts)]
+#[instruction(quest_key: u64)]
+pub struct SetAnswerOptions<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
+        bump = governance_item.bump,
+        constraint = governance_item.creator == creator.key() @ GovernanceError::Unauthorized
+    )]
+    pub governance_item: Account<'info, GovernanceItem>,
+
+    pub creator: Signer<'info>,
+}
+
+/// Lets the item's creator fix the answer options while the quest vote is still open,
+/// so voters approve them together with the question. `advance_governance_item`
+/// opens the answer phase with these options.
+pub fn set_answer_options(
+    ctx: Context<SetAnswerOptions>,
+    quest_key: u64,
+    answer_keys: Vec<u64>,
+) -> Result<()> {
+    let governance_item = &mut ctx.accounts.governance_item;
+    let clock = Clock::get()?;
+
+    require!(
+        governance_item.quest_result == QuestResult::Pending,
+        GovernanceError::QuestAlreadyFinalized
+    );
+    require!(
+        clock.unix_timestamp <= governance_item.quest_end_time,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    validate_answer_keys(&answer_keys)?;
+
+    governance_item.answer_keys = answer_keys.clone();
+
+    emit!(AnswerOptionsSet {
+        quest_key,
+        answer_keys,
+        set_by: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AnswerOptionsSet {
+    pub quest_key: u64,
+    pub answer_keys: Vec<u64>,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
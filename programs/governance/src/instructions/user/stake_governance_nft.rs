@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use mpl_token_metadata::accounts::Metadata as MetaplexMetadata;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_SEED, SUPPLY_CHECKPOINTS_SEED, VOTER_STAKE_SEED};
+
+#[derive(Accounts)]
+pub struct StakeGovernanceNft<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        constraint = governance.collection_mint != Pubkey::default() @ GovernanceError::CollectionNotCreated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + VoterStake::INIT_SPACE,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    /// Voter's checkpoint history, rewritten with the new voting power
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + VoterCheckpoints::INIT_SPACE,
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    /// Global supply history, checkpointed with every stake
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + SupplyCheckpoints::INIT_SPACE,
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    /// CHECK: Deserialized and checked against the governance collection
+    pub metadata_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = voter,
+        constraint = voter_nft_account.amount == 1 @ GovernanceError::InvalidNftAmount
+    )]
+    pub voter_nft_account: Account<'info, TokenAccount>,
+
+    /// Escrow holding the NFT, owned by the voter's stake PDA
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = nft_mint,
+        associated_token::authority = voter_stake,
+    )]
+    pub escrow_nft_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves a governance NFT into escrow and checkpoints the voter's new voting power.
+/// Escrowed NFTs cannot be counted by a second wallet, so checkpoints are authoritative.
+pub fn stake_governance_nft(ctx: Context<StakeGovernanceNft>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let governance = &ctx.accounts.governance;
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+
+    // Verify the NFT belongs to the governance collection
+    let metadata_data = ctx.accounts.metadata_account.try_borrow_data()?;
+    let metadata = MetaplexMetadata::safe_deserialize(&metadata_data)
+        .map_err(|_| GovernanceError::NftNotInCollection)?;
+    let collection_verified = metadata.collection
+        .as_ref()
+        .map(|c| c.verified && c.key == governance.collection_mint)
+        .unwrap_or(false);
+    require!(collection_verified, GovernanceError::NftNotInCollection);
+    drop(metadata_data);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.voter_nft_account.to_account_info(),
+        to: ctx.accounts.escrow_nft_account.to_account_info(),
+        authority: ctx.accounts.voter.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.init_if_needed(ctx.accounts.voter.key(), ctx.bumps.voter_stake);
+    require!(!voter_stake.is_delegating(), GovernanceError::VotesDelegated);
+    voter_stake.staked_count = voter_stake
+        .staked_count
+        .checked_add(1)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    let voter_checkpoints = &mut ctx.accounts.voter_checkpoints;
+    if voter_checkpoints.voter == Pubkey::default() {
+        voter_checkpoints.voter = ctx.accounts.voter.key();
+        voter_checkpoints.checkpoints = Vec::new();
+        voter_checkpoints.bump = ctx.bumps.voter_checkpoints;
+    }
+    voter_checkpoints.update_checkpoint(clock.slot, voter_stake.voting_power()?)?;
+
+    let supply_checkpoints = &mut ctx.accounts.supply_checkpoints;
+    if supply_checkpoints.checkpoints.is_empty() {
+        supply_checkpoints.bump = ctx.bumps.supply_checkpoints;
+    }
+    supply_checkpoints.update_staked(clock.slot, 1, true)?;
+
+    emit!(GovernanceNftStaked {
+        voter: ctx.accounts.voter.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+        staked_count: voter_stake.staked_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceNftStaked {
+    pub voter: Pubkey,
+    pub nft_mint: Pubkey,
+    /// NFTs in escrow after this stake
+    pub staked_count: u8,
+    pub slot: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{DELEGATE_INDEX_SEED, VOTER_STAKE_SEED};
+
+#[derive(Accounts)]
+pub struct UndelegateVotes<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, delegator.key().as_ref()],
+        bump = delegator_stake.bump,
+        constraint = delegator_stake.is_delegating() @ GovernanceError::VotesNotDelegated
+    )]
+    pub delegator_stake: Account<'info, VoterStake>,
+
+    #[account(
+        mut,
+        seeds = [b"voter_checkpoints", delegator.key().as_ref()],
+        bump = delegator_checkpoints.bump
+    )]
+    pub delegator_checkpoints: Account<'info, VoterCheckpoints>,
+
+    /// CHECK: Must be the wallet the delegator delegated to
+    #[account(
+        constraint = delegate.key() == delegator_stake.delegate @ GovernanceError::InvalidDelegate
+    )]
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, delegate.key().as_ref()],
+        bump = delegate_stake.bump
+    )]
+    pub delegate_stake: Account<'info, VoterStake>,
+
+    #[account(
+        mut,
+        seeds = [b"voter_checkpoints", delegate.key().as_ref()],
+        bump = delegate_checkpoints.bump
+    )]
+    pub delegate_checkpoints: Account<'info, VoterCheckpoints>,
+
+    #[account(
+        mut,
+        seeds = [DELEGATE_INDEX_SEED, delegate.key().as_ref()],
+        bump = delegate_index.bump
+    )]
+    pub delegate_index: Account<'info, DelegateIndex>,
+}
+
+/// Takes the delegator's staked voting power back from their delegate, checkpointing both.
+/// The delegator inherits the delegate's unstake lock, since the delegate may have voted with these NFTs.
+pub fn undelegate_votes(ctx: Context<UndelegateVotes>) -> Result<()> {
+    let delegator_key = ctx.accounts.delegator.key();
+    let delegate_key = ctx.accounts.delegate.key();
+    let clock = Clock::get()?;
+
+    let delegate_index = &mut ctx.accounts.delegate_index;
+    let position = delegate_index
+        .delegators
+        .iter()
+        .position(|entry| entry.delegator == delegator_key)
+        .ok_or(GovernanceError::VotesNotDelegated)?;
+    let entry = delegate_index.delegators.swap_remove(position);
+
+    let delegate_stake = &mut ctx.accounts.delegate_stake;
+    delegate_stake.delegated_votes = delegate_stake
+        .delegated_votes
+        .checked_sub(entry.votes)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    let delegator_stake = &mut ctx.accounts.delegator_stake;
+    delegator_stake.delegate = Pubkey::default();
+    delegator_stake.lock_until(delegate_stake.locked_until);
+
+    let delegator_votes = delegator_stake.voting_power()?;
+    let delegate_votes = delegate_stake.voting_power()?;
+    ctx.accounts.delegator_checkpoints.update_checkpoint(clock.slot, delegator_votes)?;
+    ctx.accounts.delegate_checkpoints.update_checkpoint(clock.slot, delegate_votes)?;
+
+    emit!(VotesUndelegated {
+        delegator: delegator_key,
+        delegate: delegate_key,
+        votes: entry.votes,
+        delegator_votes,
+        delegate_votes,
+        delegator_count: delegate_index.delegators.len() as u8,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VotesUndelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    /// Staked NFTs returned to the delegator
+    pub votes: u8,
+    /// Checkpointed voting power of each side after the move
+    pub delegator_votes: u8,
+    pub delegate_votes: u8,
+    pub delegator_count: u8,
+    pub slot: u64,
+    pub timestamp: i64,
+}
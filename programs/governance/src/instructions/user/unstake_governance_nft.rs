@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{SUPPLY_CHECKPOINTS_SEED, VOTER_STAKE_SEED};
+
+#[derive(Accounts)]
+pub struct UnstakeGovernanceNft<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump = voter_stake.bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    #[account(
+        mut,
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    #[account(
+        mut,
+        seeds = [SUPPLY_CHECKPOINTS_SEED],
+        bump = supply_checkpoints.bump
+    )]
+    pub supply_checkpoints: Box<Account<'info, SupplyCheckpoints>>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = voter_stake,
+        constraint = escrow_nft_account.amount == 1 @ GovernanceError::InvalidNftAmount
+    )]
+    pub escrow_nft_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = nft_mint,
+        associated_token::authority = voter,
+    )]
+    pub voter_nft_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Returns an escrowed governance NFT to the voter and checkpoints the reduced voting power.
+/// Blocked until every phase the voter has voted in has ended; allowed while governance is paused.
+pub fn unstake_governance_nft(ctx: Context<UnstakeGovernanceNft>) -> Result<()> {
+    let voter_stake = &ctx.accounts.voter_stake;
+    let clock = Clock::get()?;
+
+    require!(voter_stake.staked_count > 0, GovernanceError::NoStakedNfts);
+    require!(!voter_stake.is_delegating(), GovernanceError::VotesDelegated);
+    require!(
+        clock.unix_timestamp > voter_stake.locked_until,
+        GovernanceError::StakeLocked
+    );
+
+    let voter_key = ctx.accounts.voter.key();
+    let stake_bump = voter_stake.bump;
+    let signer_seeds: [&[u8]; 3] = [VOTER_STAKE_SEED, voter_key.as_ref(), &[stake_bump]];
+    let signer_seeds_refs: [&[&[u8]]; 1] = [&signer_seeds];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.escrow_nft_account.to_account_info(),
+        to: ctx.accounts.voter_nft_account.to_account_info(),
+        authority: ctx.accounts.voter_stake.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        &signer_seeds_refs,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    // The escrow is empty now, return its rent to the voter
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow_nft_account.to_account_info(),
+        destination: ctx.accounts.voter.to_account_info(),
+        authority: ctx.accounts.voter_stake.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        &signer_seeds_refs,
+    );
+    token::close_account(cpi_ctx)?;
+
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.staked_count -= 1;
+
+    let voter_checkpoints = &mut ctx.accounts.voter_checkpoints;
+    voter_checkpoints.update_checkpoint(clock.slot, voter_stake.voting_power()?)?;
+    ctx.accounts.supply_checkpoints.update_staked(clock.slot, 1, false)?;
+
+    emit!(GovernanceNftUnstaked {
+        voter: voter_key,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        staked_count: voter_stake.staked_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GovernanceNftUnstaked {
+    pub voter: Pubkey,
+    pub nft_mint: Pubkey,
+    /// NFTs left in escrow
+    pub staked_count: u8,
+    pub slot: u64,
+    pub timestamp: i64,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::errors::GovernanceError;
 use crate::states::*;
-use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_SEED, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, ANSWER_VOTE_SEED};
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_SEED, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, ANSWER_VOTE_SEED, VOTER_STAKE_SEED};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64, answer_key: u64)]
@@ -63,20 +63,42 @@ pub struct VoteAnswer<'info> {
     )]
     pub voter_checkpoints: Account<'info, VoterCheckpoints>,
 
+    /// Voter's NFT escrow; voting locks it until the end of this phase
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + VoterStake::INIT_SPACE,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn vote_answer(ctx: Context<VoteAnswer>, quest_key: u64, answer_key: u64) -> Result<()> {
-    let governance_item = &ctx.accounts.governance_item;
+    let governance_item = &mut ctx.accounts.governance_item;
     let answer_vote = &mut ctx.accounts.answer_vote;
     let answer_option = &mut ctx.accounts.answer_option;
     let answer_voter_record = &mut ctx.accounts.answer_voter_record;
+    let voter_stake = &mut ctx.accounts.voter_stake;
     let clock = Clock::get()?;
 
     require!(
-        governance_item.answer_keys.len() > 0,
+        !governance_item.is_commit_reveal(),
+        GovernanceError::CommitRevealRequired
+    );
+
+    // Creators may set answer options early, so the phase must also have been opened
+    require!(
+        !governance_item.answer_keys.is_empty() && governance_item.answer_start_time != 0,
         GovernanceError::AnswerVotingNotStarted
     );
+
+    require!(
+        clock.unix_timestamp <= governance_item.answer_end_time,
+        GovernanceError::VotingPeriodEnded
+    );
     
     require!(
         governance_item.answer_keys.iter().any(|&key| key == answer_key),
@@ -103,21 +125,24 @@ pub fn vote_answer(ctx: Context<VoteAnswer>, quest_key: u64, answer_key: u64) ->
         answer_option.bump = ctx.bumps.answer_option;
     }
 
+    // Revoked votes are zeroed, so the voter may vote again
     require!(
-        answer_voter_record.quest_key == 0,
+        answer_voter_record.quest_key == 0 || answer_voter_record.vote_count == 0,
         GovernanceError::AlreadyVoted
     );
 
     let voter_checkpoints = &ctx.accounts.voter_checkpoints;
     let config = &ctx.accounts.config;
 
-    let vote_weight_u8 = voter_checkpoints.get_past_votes(governance_item.start_slot);
+    let voting_power = voter_checkpoints.get_past_votes(governance_item.start_slot);
 
     require!(
-        vote_weight_u8 >= config.min_required_nft,
+        voting_power >= config.min_required_nft,
         GovernanceError::InsufficientNfts
     );
 
+    let vote_weight_u8 = config.vote_weighting.weight(voting_power, config.max_votable_nft);
+
     // Record the vote
     answer_voter_record.quest_key = quest_key;
     answer_voter_record.voter = ctx.accounts.voter.key();
@@ -127,6 +152,10 @@ pub fn vote_answer(ctx: Context<VoteAnswer>, quest_key: u64, answer_key: u64) ->
     answer_voter_record.rewarded = false;
     answer_voter_record.bump = ctx.bumps.answer_voter_record;
 
+    // Initialize voter_stake if needed
+    voter_stake.init_if_needed(ctx.accounts.voter.key(), ctx.bumps.voter_stake);
+    voter_stake.lock_until(governance_item.answer_end_time);
+
     // Update answer option votes
     answer_option.total_votes = answer_option
         .total_votes
@@ -138,6 +167,34 @@ pub fn vote_answer(ctx: Context<VoteAnswer>, quest_key: u64, answer_key: u64) ->
         .total_voted
         .checked_add(vote_weight_u8 as u64)
         .ok_or(GovernanceError::MathOverflow)?;
+    governance_item.add_answer_voter()?;
+    governance_item.answer_nft_turnout = governance_item
+        .answer_nft_turnout
+        .checked_add(voting_power as u64)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    emit!(VoteAnswerCast {
+        quest_key,
+        answer_key,
+        voter: ctx.accounts.voter.key(),
+        votes: vote_weight_u8 as u64,
+        snapshot_slot: governance_item.start_slot,
+        answer_total_votes: answer_option.total_votes,
+        total_voted: answer_vote.total_voted,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
+
+#[event]
+pub struct VoteAnswerCast {
+    pub quest_key: u64,
+    pub answer_key: u64,
+    pub voter: Pubkey,
+    pub votes: u64,
+    pub snapshot_slot: u64,
+    pub answer_total_votes: u64,
+    pub total_voted: u64,
+    pub timestamp: i64,
+}
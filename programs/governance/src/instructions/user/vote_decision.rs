@@ -13,6 +13,7 @@ pub struct VoteDecision<'info> {
     pub config: Account<'info, GovernanceConfig>,
 
     #[account(
+        mut,
         seeds = [GOVERNANCE_ITEM_SEED, quest_key.to_le_bytes().as_ref()],
         bump = governance_item.bump
     )]
@@ -40,6 +41,13 @@ pub struct VoteDecision<'info> {
     )]
     pub quest_voter_record: Account<'info, QuestVoterRecord>,
 
+    /// Voter's checkpoint history, read for the staked NFTs behind the vote
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
     #[account(mut)]
     pub voter: Signer<'info>,
 
@@ -53,7 +61,7 @@ pub fn vote_decision(
     vote_choice: DecisionVoteChoice,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
-    let governance_item = &ctx.accounts.governance_item;
+    let governance_item = &mut ctx.accounts.governance_item;
     let decision_vote = &mut ctx.accounts.decision_vote;
     let voter_record = &mut ctx.accounts.voter_record;
     let quest_voter_record = &ctx.accounts.quest_voter_record;
@@ -76,8 +84,9 @@ pub fn vote_decision(
         GovernanceError::VotingPeriodEnded
     );
 
+    // Revoked votes are zeroed, so the voter may vote again
     require!(
-        voter_record.quest_key == 0,
+        voter_record.votes == 0,
         GovernanceError::AlreadyVoted
     );
 
@@ -99,6 +108,11 @@ pub fn vote_decision(
     }
 
     decision_vote.total_voted += 1;
+    let nft_count = ctx.accounts.voter_checkpoints.get_past_votes(governance_item.start_slot);
+    governance_item.decision_nft_turnout = governance_item
+        .decision_nft_turnout
+        .checked_add(nft_count as u64)
+        .ok_or(GovernanceError::MathOverflow)?;
 
     voter_record.quest_key = quest_key;
     voter_record.voter = ctx.accounts.voter.key();
@@ -112,6 +126,10 @@ pub fn vote_decision(
         vote_choice: vote_choice.clone(),
         voter: ctx.accounts.voter.key(),
         votes: voting_power,
+        count_success: decision_vote.count_success,
+        count_adjourn: decision_vote.count_adjourn,
+        total_voted: decision_vote.total_voted,
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -122,5 +140,10 @@ pub struct VoteDecisionCast {
     pub quest_key: u64,
     pub vote_choice: DecisionVoteChoice,
     pub voter: Pubkey,
+    /// Votes the voter counted in the quest phase
     pub votes: u64,
+    pub count_success: u64,
+    pub count_adjourn: u64,
+    pub total_voted: u64,
+    pub timestamp: i64,
 }
\ No newline at end of file
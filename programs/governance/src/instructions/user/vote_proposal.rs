@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::errors::GovernanceError;
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, PROPOSAL_VOTER_SEED, VOTER_STAKE_SEED};
+use crate::instructions::user::create_proposal::PROPOSAL_SEED;
+
+#[derive(Accounts)]
+#[instruction(proposal_key: u64)]
+pub struct VoteProposal<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal_key.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + ProposalVoterRecord::INIT_SPACE,
+        seeds = [PROPOSAL_VOTER_SEED, proposal_key.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_record: Account<'info, ProposalVoterRecord>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Voter's checkpoint history, including votes delegated to them
+    #[account(
+        seeds = [b"voter_checkpoints", voter.key().as_ref()],
+        bump = voter_checkpoints.bump
+    )]
+    pub voter_checkpoints: Account<'info, VoterCheckpoints>,
+
+    /// Voter's NFT escrow; voting locks it until the proposal ends
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + VoterStake::INIT_SPACE,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Casts a Yes/No vote on a proposal, weighted by the voter's checkpoint at `start_block`.
+/// The weight is added to `total_vote` and to the chosen side's tally, which
+/// `set_proposal_result` later decides the result from.
+pub fn vote_proposal(
+    ctx: Context<VoteProposal>,
+    proposal_key: u64,
+    vote_choice: ProposalResult,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
+    let voter_record = &mut ctx.accounts.voter_record;
+    let voter_checkpoints = &ctx.accounts.voter_checkpoints;
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    let clock = Clock::get()?;
+
+    require!(!config.paused, GovernanceError::GovernancePaused);
+    require!(
+        vote_choice != ProposalResult::Pending,
+        GovernanceError::InvalidParameter
+    );
+    require!(
+        proposal.result == ProposalResult::Pending,
+        GovernanceError::AlreadyFinalized
+    );
+    require!(
+        clock.unix_timestamp <= proposal.end_time,
+        GovernanceError::VotingPeriodEnded
+    );
+    require!(voter_record.vote_count == 0, GovernanceError::AlreadyVoted);
+
+    let voting_power = voter_checkpoints.get_past_votes(proposal.start_block);
+    require!(voting_power >= 1, GovernanceError::InsufficientVotingPower);
+    let votes = config.vote_weighting.weight(voting_power, config.max_votable_nft);
+
+    proposal.total_vote = proposal
+        .total_vote
+        .checked_add(votes as u16)
+        .ok_or(GovernanceError::MathOverflow)?;
+    let tally = match vote_choice {
+        ProposalResult::Yes => &mut proposal.yes_vote,
+        _ => &mut proposal.no_vote,
+    };
+    *tally = tally
+        .checked_add(votes as u16)
+        .ok_or(GovernanceError::MathOverflow)?;
+
+    voter_record.proposal_key = proposal_key;
+    voter_record.voter = ctx.accounts.voter.key();
+    voter_record.vote_count = votes;
+    voter_record.vote_choice = vote_choice;
+    voter_record.timestamp = clock.unix_timestamp;
+    voter_record.bump = ctx.bumps.voter_record;
+
+    voter_stake.init_if_needed(ctx.accounts.voter.key(), ctx.bumps.voter_stake);
+    voter_stake.lock_until(proposal.end_time);
+
+    emit!(VoteProposalCast {
+        proposal_key,
+        vote_choice,
+        voter: ctx.accounts.voter.key(),
+        votes: votes as u64,
+        voting_power,
+        snapshot_slot: proposal.start_block,
+        total_vote: proposal.total_vote,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteProposalCast {
+    pub proposal_key: u64,
+    pub vote_choice: ProposalResult,
+    pub voter: Pubkey,
+    /// Votes counted after the per-voter cap
+    pub votes: u64,
+    /// Checkpointed voting power at the snapshot slot, delegations included
+    pub voting_power: u8,
+    pub snapshot_slot: u64,
+    pub total_vote: u16,
+    pub timestamp: i64,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::states::*;
 use crate::errors::GovernanceError;
-use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED, QUEST_VOTER_SEED};
+use crate::constant::{ACCOUNT_DISCRIMINATOR, GOVERNANCE_CONFIG_SEED, GOVERNANCE_ITEM_SEED, QUEST_VOTE_SEED, QUEST_VOTER_SEED, VOTER_STAKE_SEED};
 
 #[derive(Accounts)]
 #[instruction(quest_key: u64)]
@@ -49,6 +49,16 @@ pub struct VoteQuest<'info> {
     )]
     pub voter_checkpoints: Account<'info, VoterCheckpoints>,
 
+    /// Voter's NFT escrow; voting locks it until the end of this phase
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ACCOUNT_DISCRIMINATOR + VoterStake::INIT_SPACE,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
@@ -59,10 +69,11 @@ pub fn vote_quest(
     vote_choice: QuestVoteChoice,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
-    let governance_item = &ctx.accounts.governance_item;
+    let governance_item = &mut ctx.accounts.governance_item;
     let quest_vote = &mut ctx.accounts.quest_vote;
     let voter_record = &mut ctx.accounts.voter_record;
     let voter_checkpoints = &mut ctx.accounts.voter_checkpoints;
+    let voter_stake = &mut ctx.accounts.voter_stake;
     let clock = &ctx.accounts.clock;
 
     // Initialize voter_checkpoints if needed
@@ -72,6 +83,9 @@ pub fn vote_quest(
         voter_checkpoints.bump = ctx.bumps.voter_checkpoints;
     }
 
+    // Initialize voter_stake if needed
+    voter_stake.init_if_needed(ctx.accounts.voter.key(), ctx.bumps.voter_stake);
+
     require!(!config.paused, GovernanceError::GovernancePaused);
     
     require!(
@@ -105,8 +119,8 @@ pub fn vote_quest(
         GovernanceError::InsufficientVotingPower
     );
     
-    // CAP AT MAX_VOTABLE_NFT
-    let mut capped_voting_power = voting_power.min(config.max_votable_nft);
+    // APPLY THE CONFIGURED WEIGHTING CURVE
+    let mut capped_voting_power = config.vote_weighting.weight(voting_power, config.max_votable_nft);
     
     // ADJUST IF WOULD EXCEED MAX_TOTAL_VOTE
     let remaining_votes = config.max_total_vote.saturating_sub(total_current_votes);
@@ -122,6 +136,10 @@ pub fn vote_quest(
     }
     
     quest_vote.total_voted += 1;
+    governance_item.quest_nft_turnout = governance_item
+        .quest_nft_turnout
+        .checked_add(voting_power as u64)
+        .ok_or(GovernanceError::MathOverflow)?;
     
     voter_record.quest_key = quest_key;
     voter_record.voter = ctx.accounts.voter.key();
@@ -129,12 +147,20 @@ pub fn vote_quest(
     voter_record.vote_choice = vote_choice.clone();
     voter_record.timestamp = clock.unix_timestamp;
     voter_record.bump = ctx.bumps.voter_record;
+
+    voter_stake.lock_until(governance_item.quest_end_time);
     
     emit!(VoteQuestCast {
         quest_key,
         vote_choice: vote_choice.clone(),
         voter: ctx.accounts.voter.key(),
         votes: capped_voting_power as u64,
+        voting_power,
+        snapshot_slot: governance_item.start_slot,
+        count_approver: quest_vote.count_approver,
+        count_rejector: quest_vote.count_rejector,
+        total_voted: quest_vote.total_voted,
+        timestamp: clock.unix_timestamp,
     });
     
     Ok(())
@@ -145,5 +171,13 @@ pub struct VoteQuestCast {
     pub quest_key: u64,
     pub vote_choice: QuestVoteChoice,
     pub voter: Pubkey,
+    /// Votes counted after the per-voter and total caps
     pub votes: u64,
+    /// Checkpointed voting power at the snapshot slot, delegations included, before caps
+    pub voting_power: u8,
+    pub snapshot_slot: u64,
+    pub count_approver: u64,
+    pub count_rejector: u64,
+    pub total_voted: u64,
+    pub timestamp: i64,
 }
\ No newline at end of file
@@ -4,6 +4,8 @@ pub mod errors;
 pub mod instructions;
 pub mod states;
 pub mod constant;
+pub mod utils;
+pub mod weighting;
 
 use instructions::*;
 use states::*;
@@ -83,6 +85,13 @@ pub mod boomplay_governance {
         instructions::set_reward_amount::set_reward_amount(ctx, reward_amount)
     }
 
+    pub fn set_min_reward_pool(
+        ctx: Context<SetMinRewardPool>,
+        min_reward_pool: u64,
+    ) -> Result<()> {
+        instructions::set_min_reward_pool::set_min_reward_pool(ctx, min_reward_pool)
+    }
+
     pub fn set_quest_duration_hours(
         ctx: Context<SetQuestDurationHours>,
         hours: u64,
@@ -110,6 +119,49 @@ pub mod boomplay_governance {
         instructions::update_base_token_mint::update_base_token_mint(ctx)
     }
 
+    pub fn set_vote_thresholds(
+        ctx: Context<SetVoteThresholds>,
+        quorum_bps: u16,
+        approval_bps: u16,
+    ) -> Result<()> {
+        instructions::set_vote_thresholds::set_vote_thresholds(ctx, quorum_bps, approval_bps)
+    }
+
+    pub fn set_answer_tie_break(
+        ctx: Context<SetAnswerTieBreak>,
+        tie_break: AnswerTieBreak,
+    ) -> Result<()> {
+        instructions::set_answer_tie_break::set_answer_tie_break(ctx, tie_break)
+    }
+
+    pub fn set_vote_weighting(
+        ctx: Context<SetVoteWeighting>,
+        vote_weighting: VoteWeighting,
+    ) -> Result<()> {
+        instructions::set_vote_weighting::set_vote_weighting(ctx, vote_weighting)
+    }
+
+    pub fn set_phase_rewards(
+        ctx: Context<SetPhaseRewards>,
+        quest_reward_token: u64,
+        decision_reward_token: u64,
+    ) -> Result<()> {
+        instructions::set_phase_rewards::set_phase_rewards(ctx, quest_reward_token, decision_reward_token)
+    }
+
+    // Account Migration Instructions
+    pub fn migrate_governance_config(ctx: Context<MigrateGovernanceConfig>) -> Result<()> {
+        instructions::migrate_governance_config::migrate_governance_config(ctx)
+    }
+
+    pub fn migrate_governance_item(ctx: Context<MigrateGovernanceItem>) -> Result<()> {
+        instructions::migrate_governance_item::migrate_governance_item(ctx)
+    }
+
+    pub fn migrate_proposal(ctx: Context<MigrateProposal>) -> Result<()> {
+        instructions::migrate_proposal::migrate_proposal(ctx)
+    }
+
     // Quest Management Instructions
     pub fn set_quest_result(
         ctx: Context<SetQuestResult>,
@@ -197,11 +249,20 @@ pub mod boomplay_governance {
         instructions::set_answer_end_time::set_answer_end_time(ctx, quest_key, new_end_time)
     }
 
+    pub fn set_answer_commit_reveal(
+        ctx: Context<SetAnswerCommitReveal>,
+        quest_key: u64,
+        reveal_hours: u64,
+    ) -> Result<()> {
+        instructions::set_answer_commit_reveal::set_answer_commit_reveal(ctx, quest_key, reveal_hours)
+    }
+
     pub fn finalize_answer(
         ctx: Context<FinalizeAnswer>,
         quest_key: u64,
+        tie_break_answer: Option<u64>,
     ) -> Result<()> {
-        instructions::finalize_answer::finalize_answer(ctx, quest_key)
+        instructions::finalize_answer::finalize_answer(ctx, quest_key, tie_break_answer)
     }
 
     // Reward Distribution Instructions
@@ -216,10 +277,8 @@ pub mod boomplay_governance {
     pub fn set_proposal_result(
         ctx: Context<SetProposalResult>,
         proposal_key: u64,
-        result: ProposalResult,
-        result_vote: u16,
     ) -> Result<()> {
-        instructions::set_proposal_result::set_proposal_result(ctx, proposal_key, result, result_vote)
+        instructions::set_proposal_result::set_proposal_result(ctx, proposal_key)
     }
 
     // ========================================
@@ -259,6 +318,43 @@ pub mod boomplay_governance {
         instructions::vote_quest::vote_quest(ctx, quest_key, vote_choice)
     }
 
+    pub fn set_answer_options(
+        ctx: Context<SetAnswerOptions>,
+        quest_key: u64,
+        answer_keys: Vec<u64>,
+    ) -> Result<()> {
+        instructions::set_answer_options::set_answer_options(ctx, quest_key, answer_keys)
+    }
+
+    pub fn advance_governance_item(
+        ctx: Context<AdvanceGovernanceItem>,
+        quest_key: u64,
+    ) -> Result<()> {
+        instructions::advance_governance_item::advance_governance_item(ctx, quest_key)
+    }
+
+    pub fn fund_reward_pool(
+        ctx: Context<FundRewardPool>,
+        quest_key: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_reward_pool::fund_reward_pool(ctx, quest_key, amount)
+    }
+
+    pub fn claim_governance_rewards(
+        ctx: Context<ClaimGovernanceRewards>,
+        quest_key: u64,
+    ) -> Result<()> {
+        instructions::claim_governance_rewards::claim_governance_rewards(ctx, quest_key)
+    }
+
+    pub fn refund_reward_pool(
+        ctx: Context<RefundRewardPool>,
+        quest_key: u64,
+    ) -> Result<()> {
+        instructions::refund_reward_pool::refund_reward_pool(ctx, quest_key)
+    }
+
     pub fn start_decision(
         ctx: Context<StartDecision>,
         quest_key: u64,
@@ -282,9 +378,89 @@ pub mod boomplay_governance {
         instructions::vote_answer::vote_answer(ctx, quest_key, answer_key)
     }
 
+    pub fn commit_answer_vote(
+        ctx: Context<CommitAnswerVote>,
+        quest_key: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_answer_vote::commit_answer_vote(ctx, quest_key, commitment)
+    }
+
+    pub fn reveal_answer_vote(
+        ctx: Context<RevealAnswerVote>,
+        quest_key: u64,
+        answer_key: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_answer_vote::reveal_answer_vote(ctx, quest_key, answer_key, salt)
+    }
+
+    pub fn change_quest_vote(
+        ctx: Context<ChangeQuestVote>,
+        quest_key: u64,
+        new_choice: Option<QuestVoteChoice>,
+    ) -> Result<()> {
+        instructions::change_quest_vote::change_quest_vote(ctx, quest_key, new_choice)
+    }
+
+    pub fn change_decision_vote(
+        ctx: Context<ChangeDecisionVote>,
+        quest_key: u64,
+        new_choice: Option<DecisionVoteChoice>,
+    ) -> Result<()> {
+        instructions::change_decision_vote::change_decision_vote(ctx, quest_key, new_choice)
+    }
+
+    pub fn change_answer_vote(
+        ctx: Context<ChangeAnswerVote>,
+        quest_key: u64,
+        new_answer_key: Option<u64>,
+    ) -> Result<()> {
+        instructions::change_answer_vote::change_answer_vote(ctx, quest_key, new_answer_key)
+    }
+
+    pub fn vote_proposal(
+        ctx: Context<VoteProposal>,
+        proposal_key: u64,
+        vote_choice: ProposalResult,
+    ) -> Result<()> {
+        instructions::vote_proposal::vote_proposal(ctx, proposal_key, vote_choice)
+    }
+
     pub fn update_voter_checkpoint(
         ctx: Context<UpdateVoterCheckpoint>,
     ) -> Result<()> {
         instructions::update_voter_checkpoint::update_voter_checkpoint(ctx)
     }
+
+    pub fn stake_governance_nft(
+        ctx: Context<StakeGovernanceNft>,
+    ) -> Result<()> {
+        instructions::stake_governance_nft::stake_governance_nft(ctx)
+    }
+
+    pub fn unstake_governance_nft(
+        ctx: Context<UnstakeGovernanceNft>,
+    ) -> Result<()> {
+        instructions::unstake_governance_nft::unstake_governance_nft(ctx)
+    }
+
+    pub fn delegate_votes(
+        ctx: Context<DelegateVotes>,
+    ) -> Result<()> {
+        instructions::delegate_votes::delegate_votes(ctx)
+    }
+
+    pub fn undelegate_votes(
+        ctx: Context<UndelegateVotes>,
+    ) -> Result<()> {
+        instructions::undelegate_votes::undelegate_votes(ctx)
+    }
+
+    pub fn lock_voter_stake(
+        ctx: Context<LockVoterStake>,
+        locked_until: i64,
+    ) -> Result<()> {
+        instructions::lock_voter_stake::lock_voter_stake(ctx, locked_until)
+    }
 }
\ No newline at end of file
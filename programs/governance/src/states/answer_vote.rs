@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
 
 #[account]
 #[derive(Debug, InitSpace)]
@@ -31,3 +32,94 @@ pub struct AnswerVoterRecord {
     pub rewarded: bool,
     pub bump: u8,
 }
+
+/// A hidden answer vote in commit-reveal mode, turned into an AnswerVoterRecord on reveal
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct AnswerCommit {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub commitment: [u8; 32],
+    pub vote_count: u8,
+    pub revealed: bool,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Commitment to an answer vote. Binding the quest and voter keeps a commitment
+/// from being copied by another voter or replayed on another item
+pub fn answer_commitment(quest_key: u64, voter: &Pubkey, answer_key: u64, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[
+        quest_key.to_le_bytes().as_ref(),
+        voter.as_ref(),
+        answer_key.to_le_bytes().as_ref(),
+        salt.as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Why an answer vote was finalized without a winner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CancelReason {
+    /// cancel_answer by the authority
+    Authority,
+    QuorumNotReached,
+    NoVotes,
+    /// Tied under `AnswerTieBreak::Cancel`
+    TiedVote,
+}
+
+/// Outcome of counting `(answer_key, total_votes)` pairs
+#[derive(Debug, PartialEq, Eq)]
+pub enum AnswerTally {
+    Winner(u64),
+    /// Keys tied for the most votes, in the order they were counted
+    Tied(Vec<u64>),
+    NoVotes,
+}
+
+/// Picks the answer with the most votes, reporting ties instead of resolving them
+pub fn tally_answers(tallies: &[(u64, u64)]) -> AnswerTally {
+    let top = tallies.iter().map(|&(_, votes)| votes).max().unwrap_or(0);
+    if top == 0 {
+        return AnswerTally::NoVotes;
+    }
+
+    let leaders: Vec<u64> = tallies
+        .iter()
+        .filter(|&&(_, votes)| votes == top)
+        .map(|&(key, _)| key)
+        .collect();
+
+    if leaders.len() == 1 {
+        AnswerTally::Winner(leaders[0])
+    } else {
+        AnswerTally::Tied(leaders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tally_answers() {
+        assert_eq!(tally_answers(&[(1, 3), (2, 7), (3, 5)]), AnswerTally::Winner(2));
+        assert_eq!(tally_answers(&[(4, 5), (2, 1), (9, 5)]), AnswerTally::Tied(vec![4, 9]));
+        assert_eq!(tally_answers(&[(1, 0), (2, 0)]), AnswerTally::NoVotes);
+        assert_eq!(tally_answers(&[]), AnswerTally::NoVotes);
+    }
+
+    #[test]
+    fn test_answer_commitment_binds_every_input() {
+        let voter = Pubkey::new_unique();
+        let salt = [7u8; 32];
+        let commitment = answer_commitment(1, &voter, 2, &salt);
+
+        assert_eq!(commitment, answer_commitment(1, &voter, 2, &salt));
+        assert_ne!(commitment, answer_commitment(1, &voter, 3, &salt));
+        assert_ne!(commitment, answer_commitment(1, &voter, 2, &[8u8; 32]));
+        assert_ne!(commitment, answer_commitment(1, &Pubkey::new_unique(), 2, &salt));
+        assert_ne!(commitment, answer_commitment(2, &voter, 2, &salt));
+    }
+}
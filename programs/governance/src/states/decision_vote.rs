@@ -17,6 +17,17 @@ pub struct DecisionVote {
     pub bump: u8,
 }
 
+impl DecisionVote {
+    /// The side holding strictly more weighted votes, None on a tie
+    pub fn majority(&self) -> Option<DecisionVoteChoice> {
+        match self.count_success.cmp(&self.count_adjourn) {
+            std::cmp::Ordering::Greater => Some(DecisionVoteChoice::Success),
+            std::cmp::Ordering::Less => Some(DecisionVoteChoice::Adjourn),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct DecisionVoterRecord {
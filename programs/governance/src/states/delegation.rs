@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct DelegatorEntry {
+    pub delegator: Pubkey,
+    /// Staked NFTs of the delegator at delegation time; staking changes are blocked while delegated
+    pub votes: u8,
+}
+
+/// Reverse index of the voters delegating to `delegate`.
+/// The delegate's `VoterStake::delegated_votes` always equals `total_votes()`.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct DelegateIndex {
+    pub delegate: Pubkey,
+    #[max_len(32)]  // MAX_DELEGATORS
+    pub delegators: Vec<DelegatorEntry>,
+    pub bump: u8,
+}
+
+impl DelegateIndex {
+    pub fn total_votes(&self) -> u64 {
+        self.delegators.iter().map(|entry| entry.votes as u64).sum()
+    }
+}
@@ -1,4 +1,29 @@
 use anchor_lang::prelude::*;
+use crate::constant::GOVERNANCE_CONFIG_RESERVED_SPACE;
+
+/// How finalize_answer resolves answer options tied for the most votes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AnswerTieBreak {
+    /// The tied option listed first in `answer_keys` wins
+    FirstListed,
+    /// The answer vote is cancelled with no winner
+    Cancel,
+    /// The authority picks one of the tied options when finalizing
+    Authority,
+}
+
+/// Maps a voter's checkpointed NFT count to counted votes, see `crate::weighting`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum VoteWeighting {
+    /// NFT count capped at `max_votable_nft` (the original behavior, hence the default)
+    CappedLinear,
+    /// One vote per NFT
+    Linear,
+    /// Square root of the NFT count, rounded to the nearest vote
+    SquareRoot,
+    /// One vote, plus one for every doubling of the NFT count
+    Tiered,
+}
 
 #[account]
 #[derive(Debug, InitSpace)]
@@ -16,4 +41,21 @@ pub struct GovernanceConfig {
     pub constant_reward_token: u64,
     pub total_governance: u64,
     pub bump: u8,
+    pub version: u8,
+    /// Minimum turnout in staked NFTs behind the votes, in bps of the staked supply at the
+    /// item's start_slot. 0 disables it
+    pub quorum_bps: u16,
+    /// Minimum approving NFTs, the approving share of the weighted votes applied to the NFT
+    /// turnout, in bps of the staked supply at the item's start_slot. 0 disables it
+    pub approval_bps: u16,
+    pub answer_tie_break: AnswerTieBreak,
+    pub vote_weighting: VoteWeighting,
+    /// Reward per vote for voting with the majority in the quest phase. 0 disables it
+    pub quest_reward_token: u64,
+    /// Reward per vote for voting with the majority in the decision phase. 0 disables it
+    pub decision_reward_token: u64,
+    /// Smallest funded reward pool that pays a quest's answer rewards as a share of the pool
+    /// instead of the constant per-vote reward. 0 keeps every quest on the constant reward
+    pub min_reward_pool: u64,
+    pub reserved: [u8; GOVERNANCE_CONFIG_RESERVED_SPACE],
 }
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constant::GOVERNANCE_ITEM_RESERVED_SPACE;
+use crate::errors::GovernanceError;
+use crate::states::SupplyCheckpoints;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum QuestResult {
@@ -34,4 +37,62 @@ pub struct GovernanceItem {
     #[max_len(10)]
     pub answer_keys: Vec<u64>, // Dynamic array of answer options
     pub bump: u8,
+    pub version: u8,
+    /// Seconds after answer_end_time during which committed answer votes are revealed.
+    /// 0 means answers are voted in the clear with vote_answer
+    pub reveal_duration: i64,
+    /// Tokens funded into the quest's reward pool. Reaching the config's min_reward_pool
+    /// switches answer rewards from the constant per-vote amount to a pro-rata share of the pool
+    pub reward_pool_amount: u64,
+    /// Staked NFT supply when the item was created, the base of its bps thresholds.
+    /// 0 on items created before it was recorded, which read the supply history instead
+    pub voting_supply: u64,
+    /// Voters counted in the answer vote, checked against min_total_vote like the quest and
+    /// decision voter counts; answer_vote.total_voted is their weighted sum.
+    /// Answer votes cast before this count existed are not in it
+    pub answer_voter_count: u64,
+    /// Staked NFTs behind the counted votes of each phase, the unit quorum_bps and
+    /// approval_bps are checked in. 0 on items voted on before they were tracked
+    pub quest_nft_turnout: u64,
+    pub decision_nft_turnout: u64,
+    pub answer_nft_turnout: u64,
+    /// Winning votes already paid from the reward pool; the claim completing them takes the rest
+    pub reward_pool_claimed_votes: u64,
+    /// Whether answer rewards are paid from the reward pool, fixed when the answer is
+    /// finalized so later min_reward_pool changes don't move claims between modes
+    pub pays_reward_pool: bool,
+    pub reserved: [u8; GOVERNANCE_ITEM_RESERVED_SPACE],
+}
+
+impl GovernanceItem {
+    pub fn is_commit_reveal(&self) -> bool {
+        self.reveal_duration > 0
+    }
+
+    /// End of the answer phase: the reveal window in commit-reveal mode, the voting window otherwise
+    pub fn answer_close_time(&self) -> i64 {
+        self.answer_end_time + self.reveal_duration
+    }
+
+    /// Whether the funded pool reaches a non-zero `min_reward_pool`; recorded in
+    /// `pays_reward_pool` when the answer is finalized
+    pub fn reaches_reward_pool(&self, min_reward_pool: u64) -> bool {
+        min_reward_pool > 0 && self.reward_pool_amount >= min_reward_pool
+    }
+
+    /// Staked supply the quorum and approval thresholds are measured against
+    pub fn voting_supply(&self, supply_checkpoints: &SupplyCheckpoints) -> Result<u64> {
+        if self.voting_supply > 0 {
+            return Ok(self.voting_supply);
+        }
+        supply_checkpoints.get_past_supply(self.start_slot)
+    }
+
+    pub fn add_answer_voter(&mut self) -> Result<()> {
+        self.answer_voter_count = self
+            .answer_voter_count
+            .checked_add(1)
+            .ok_or(GovernanceError::MathOverflow)?;
+        Ok(())
+    }
 }
\ No newline at end of file
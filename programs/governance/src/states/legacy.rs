@@ -0,0 +1,138 @@
+//! Layouts of accounts created before versioning was introduced (version 0).
+//! They are only read by the migrate_* instructions, which rewrite them in the current layout.
+
+use anchor_lang::prelude::*;
+use crate::constant::{GOVERNANCE_CONFIG_RESERVED_SPACE, GOVERNANCE_ITEM_RESERVED_SPACE};
+use super::{
+    AnswerTieBreak, DecisionResult, VoteWeighting, GovernanceConfig, GovernanceItem, Proposal, ProposalResult,
+    QuestResult,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GovernanceConfigV0 {
+    pub authority: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub base_nft_collection: Pubkey,
+    pub treasury_bump: u8,
+    pub paused: bool,
+    pub min_total_vote: u64,
+    pub max_total_vote: u64,
+    pub min_required_nft: u8,
+    pub max_votable_nft: u8,
+    pub duration_hours: u64,
+    pub constant_reward_token: u64,
+    pub total_governance: u64,
+    pub bump: u8,
+}
+
+impl From<GovernanceConfigV0> for GovernanceConfig {
+    fn from(legacy: GovernanceConfigV0) -> Self {
+        Self {
+            authority: legacy.authority,
+            base_token_mint: legacy.base_token_mint,
+            base_nft_collection: legacy.base_nft_collection,
+            treasury_bump: legacy.treasury_bump,
+            paused: legacy.paused,
+            min_total_vote: legacy.min_total_vote,
+            max_total_vote: legacy.max_total_vote,
+            min_required_nft: legacy.min_required_nft,
+            max_votable_nft: legacy.max_votable_nft,
+            duration_hours: legacy.duration_hours,
+            constant_reward_token: legacy.constant_reward_token,
+            total_governance: legacy.total_governance,
+            bump: legacy.bump,
+            version: 0,
+            quorum_bps: 0,
+            approval_bps: 0,
+            answer_tie_break: AnswerTieBreak::FirstListed,
+            vote_weighting: VoteWeighting::CappedLinear,
+            quest_reward_token: 0,
+            decision_reward_token: 0,
+            min_reward_pool: 0,
+            reserved: [0; GOVERNANCE_CONFIG_RESERVED_SPACE],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GovernanceItemV0 {
+    pub quest_key: u64,
+    pub question: String,
+    pub creator: Pubkey,
+    pub quest_result: QuestResult,
+    pub decision_result: DecisionResult,
+    pub answer_result: u64,
+    pub start_slot: u64,
+    pub quest_start_time: i64,
+    pub quest_end_time: i64,
+    pub decision_start_time: i64,
+    pub decision_end_time: i64,
+    pub answer_start_time: i64,
+    pub answer_end_time: i64,
+    pub answer_keys: Vec<u64>,
+    pub bump: u8,
+}
+
+impl From<GovernanceItemV0> for GovernanceItem {
+    fn from(legacy: GovernanceItemV0) -> Self {
+        Self {
+            quest_key: legacy.quest_key,
+            question: legacy.question,
+            creator: legacy.creator,
+            quest_result: legacy.quest_result,
+            decision_result: legacy.decision_result,
+            answer_result: legacy.answer_result,
+            start_slot: legacy.start_slot,
+            quest_start_time: legacy.quest_start_time,
+            quest_end_time: legacy.quest_end_time,
+            decision_start_time: legacy.decision_start_time,
+            decision_end_time: legacy.decision_end_time,
+            answer_start_time: legacy.answer_start_time,
+            answer_end_time: legacy.answer_end_time,
+            answer_keys: legacy.answer_keys,
+            bump: legacy.bump,
+            version: 0,
+            reveal_duration: 0,
+            reward_pool_amount: 0,
+            voting_supply: 0,
+            answer_voter_count: 0,
+            quest_nft_turnout: 0,
+            decision_nft_turnout: 0,
+            answer_nft_turnout: 0,
+            reward_pool_claimed_votes: 0,
+            pays_reward_pool: false,
+            reserved: [0; GOVERNANCE_ITEM_RESERVED_SPACE],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposalV0 {
+    pub proposal_key: u64,
+    pub creator: Pubkey,
+    pub title: String,
+    pub result: ProposalResult,
+    pub total_vote: u16,
+    pub result_vote: u16,
+    pub end_time: i64,
+    pub start_block: u64,
+    pub bump: u8,
+}
+
+impl From<ProposalV0> for Proposal {
+    fn from(legacy: ProposalV0) -> Self {
+        Self {
+            proposal_key: legacy.proposal_key,
+            creator: legacy.creator,
+            title: legacy.title,
+            result: legacy.result,
+            total_vote: legacy.total_vote,
+            result_vote: legacy.result_vote,
+            end_time: legacy.end_time,
+            start_block: legacy.start_block,
+            bump: legacy.bump,
+            yes_vote: 0,
+            no_vote: 0,
+        }
+    }
+}
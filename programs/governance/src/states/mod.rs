@@ -7,6 +7,11 @@ pub mod answer_vote;
 pub mod voter_record;
 pub mod proposal;
 pub mod voter_checkpoint;
+pub mod supply_checkpoint;
+pub mod voter_stake;
+pub mod delegation;
+pub mod reward_claim;
+pub mod legacy;
 
 pub use governance::*;
 pub use governance_config::*;
@@ -17,3 +22,8 @@ pub use answer_vote::*;
 pub use voter_record::*;
 pub use proposal::*;
 pub use voter_checkpoint::*;
+pub use supply_checkpoint::*;
+pub use voter_stake::*;
+pub use delegation::*;
+pub use reward_claim::*;
+pub use legacy::*;
@@ -26,4 +26,17 @@ pub struct Proposal {
     pub end_time: i64,
     pub start_block: u64,
     pub bump: u8,
-}
\ No newline at end of file
+    /// Weighted votes cast for each side; set_proposal_result decides the result from them
+    pub yes_vote: u16,
+    pub no_vote: u16,
+}
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ProposalVoterRecord {
+    pub proposal_key: u64,
+    pub voter: Pubkey,
+    pub vote_count: u8,
+    pub vote_choice: ProposalResult,
+    pub timestamp: i64,
+    pub bump: u8,
+}
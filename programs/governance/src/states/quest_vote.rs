@@ -17,6 +17,17 @@ pub struct QuestVote {
     pub bump: u8,
 }
 
+impl QuestVote {
+    /// The side holding strictly more weighted votes, None on a tie
+    pub fn majority(&self) -> Option<QuestVoteChoice> {
+        match self.count_approver.cmp(&self.count_rejector) {
+            std::cmp::Ordering::Greater => Some(QuestVoteChoice::Approve),
+            std::cmp::Ordering::Less => Some(QuestVoteChoice::Reject),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct QuestVoterRecord {
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Quest- and decision-phase rewards a voter has claimed on a quest.
+/// Kept apart from the voter records, whose layout predates these rewards.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PhaseRewardClaim {
+    pub quest_key: u64,
+    pub voter: Pubkey,
+    pub quest_rewarded: bool,
+    pub decision_rewarded: bool,
+    pub bump: u8,
+}
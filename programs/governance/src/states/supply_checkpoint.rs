@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use crate::constant::{BPS_DENOMINATOR, MAX_SUPPLY_CHECKPOINTS};
+use crate::errors::GovernanceError;
+
+/// Governance NFT supply at a specific slot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct SupplyCheckpoint {
+    pub slot: u64,
+    pub minted: u64,  // Governance NFTs minted so far
+    pub staked: u64,  // NFTs in escrow, i.e. the voting supply
+}
+
+/// Global history of the governance NFT supply, the total counterpart of VoterCheckpoints.
+/// Only the latest MAX_SUPPLY_CHECKPOINTS entries are kept.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct SupplyCheckpoints {
+    #[max_len(128)]  // MAX_SUPPLY_CHECKPOINTS
+    pub checkpoints: Vec<SupplyCheckpoint>,
+    pub bump: u8,
+}
+
+impl SupplyCheckpoints {
+    /// Binary search for the voting (staked) supply at or before the target slot.
+    /// Fails once the retained history has been truncated past the target slot.
+    pub fn get_past_supply(&self, target_slot: u64) -> Result<u64> {
+        let checkpoints = &self.checkpoints;
+
+        if checkpoints.is_empty() {
+            return Ok(0);
+        }
+
+        let mut low = 0usize;
+        let mut high = checkpoints.len();
+
+        while low < high {
+            let mid = (low + high) / 2;
+            if checkpoints[mid].slot > target_slot {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        if high == 0 {
+            // Nothing is ever dropped before the history fills up, so the supply was still 0
+            require!(
+                checkpoints.len() < MAX_SUPPLY_CHECKPOINTS,
+                GovernanceError::SupplyHistoryUnavailable
+            );
+            return Ok(0);
+        }
+        Ok(checkpoints[high - 1].staked)
+    }
+
+    /// Current voting (staked) supply
+    pub fn staked(&self) -> u64 {
+        self.latest().1
+    }
+
+    fn latest(&self) -> (u64, u64) {
+        self.checkpoints
+            .last()
+            .map(|c| (c.minted, c.staked))
+            .unwrap_or((0, 0))
+    }
+
+    /// Records the governance's running mint count at the current slot, so NFTs minted before
+    /// the history existed are counted too
+    pub fn record_minted(&mut self, current_slot: u64, total_minted: u64) {
+        let (_, staked) = self.latest();
+        self.update_checkpoint(current_slot, total_minted, staked);
+    }
+
+    /// Records NFTs entering (`staked == true`) or leaving escrow at the current slot
+    pub fn update_staked(&mut self, current_slot: u64, amount: u64, staked: bool) -> Result<()> {
+        let (minted, current) = self.latest();
+        let new_staked = if staked {
+            current.checked_add(amount)
+        } else {
+            current.checked_sub(amount)
+        }
+        .ok_or(GovernanceError::MathOverflow)?;
+        self.update_checkpoint(current_slot, minted, new_staked);
+        Ok(())
+    }
+
+    /// Add a new checkpoint or update the one for the current slot, dropping the oldest when full
+    fn update_checkpoint(&mut self, current_slot: u64, minted: u64, staked: u64) {
+        if let Some(last) = self.checkpoints.last_mut() {
+            if last.slot == current_slot {
+                last.minted = minted;
+                last.staked = staked;
+                return;
+            }
+        }
+
+        if self.checkpoints.len() >= MAX_SUPPLY_CHECKPOINTS {
+            self.checkpoints.remove(0);
+        }
+        self.checkpoints.push(SupplyCheckpoint {
+            slot: current_slot,
+            minted,
+            staked,
+        });
+    }
+}
+
+/// Votes needed to reach `bps` of `supply`, rounded up
+pub fn bps_threshold(supply: u64, bps: u16) -> u64 {
+    let scaled = supply as u128 * bps as u128;
+    scaled.div_ceil(BPS_DENOMINATOR as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supply(entries: &[(u64, u64)]) -> SupplyCheckpoints {
+        SupplyCheckpoints {
+            checkpoints: entries
+                .iter()
+                .map(|&(slot, staked)| SupplyCheckpoint { slot, minted: staked, staked })
+                .collect(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_get_past_supply() {
+        let checkpoints = supply(&[(100, 3), (200, 10), (300, 7)]);
+
+        assert_eq!(checkpoints.get_past_supply(100).unwrap(), 3);
+        assert_eq!(checkpoints.get_past_supply(250).unwrap(), 10);
+        assert_eq!(checkpoints.get_past_supply(1_000).unwrap(), 7);
+        // Before the first checkpoint, with nothing truncated yet
+        assert_eq!(checkpoints.get_past_supply(50).unwrap(), 0);
+        assert_eq!(supply(&[]).get_past_supply(50).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_update_staked_same_slot_and_truncation() {
+        let mut checkpoints = supply(&[]);
+        checkpoints.record_minted(10, 5);
+        checkpoints.update_staked(10, 2, true).unwrap();
+        assert_eq!(checkpoints.checkpoints.len(), 1);
+        assert_eq!(checkpoints.checkpoints[0].minted, 5);
+        assert_eq!(checkpoints.get_past_supply(10).unwrap(), 2);
+
+        checkpoints.update_staked(11, 1, false).unwrap();
+        assert_eq!(checkpoints.get_past_supply(11).unwrap(), 1);
+        assert!(checkpoints.update_staked(12, 5, false).is_err());
+
+        for slot in 0..MAX_SUPPLY_CHECKPOINTS as u64 {
+            checkpoints.update_staked(100 + slot, 1, true).unwrap();
+        }
+        assert_eq!(checkpoints.checkpoints.len(), MAX_SUPPLY_CHECKPOINTS);
+        assert_eq!(checkpoints.checkpoints[0].slot, 100);
+        // Slots 10 and 11 were truncated away
+        assert!(checkpoints.get_past_supply(11).is_err());
+        assert_eq!(checkpoints.staked(), 1 + MAX_SUPPLY_CHECKPOINTS as u64);
+    }
+
+    #[test]
+    fn test_bps_threshold_rounds_up() {
+        assert_eq!(bps_threshold(1_000, 2_500), 250);
+        assert_eq!(bps_threshold(3, 5_000), 2);
+        assert_eq!(bps_threshold(7, 0), 0);
+        assert_eq!(bps_threshold(u64::MAX, 10_000), u64::MAX);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constant::MAX_VOTER_CHECKPOINTS;
 
 /// Individual checkpoint recording voting power at a specific slot
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
@@ -9,11 +10,12 @@ pub struct Checkpoint {
 
 /// Stores historical checkpoints for a voter
 /// Similar to OpenZeppelin's Votes.sol checkpoint system
+/// Only the latest MAX_VOTER_CHECKPOINTS entries are kept.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct VoterCheckpoints {
     pub voter: Pubkey,
-    #[max_len(50)]  // Store up to 50 historical checkpoints
+    #[max_len(50)]  // MAX_VOTER_CHECKPOINTS
     pub checkpoints: Vec<Checkpoint>,
     pub bump: u8,
 }
@@ -27,7 +29,7 @@ impl VoterCheckpoints {
     pub fn get_past_votes(&self, target_slot: u64) -> u8 {
         let checkpoints = &self.checkpoints;
 
-        // If no checkpoints or target is before first checkpoint, return 0
+        // If no checkpoints or target is before the retained history, return 0
         if checkpoints.is_empty() || checkpoints[0].slot > target_slot {
             return 0;
         }
@@ -49,7 +51,7 @@ impl VoterCheckpoints {
         checkpoints[high - 1].nft_count
     }
 
-    /// Add a new checkpoint or update the most recent one
+    /// Add a new checkpoint or update the most recent one, dropping the oldest when full
     /// This is called whenever a voter's NFT balance changes
     pub fn update_checkpoint(&mut self, current_slot: u64, new_nft_count: u8) -> Result<()> {
         // If this is for the same slot as the last checkpoint, update it
@@ -61,6 +63,9 @@ impl VoterCheckpoints {
         }
 
         // Otherwise, add a new checkpoint
+        if self.checkpoints.len() >= MAX_VOTER_CHECKPOINTS {
+            self.checkpoints.remove(0);
+        }
         self.checkpoints.push(Checkpoint {
             slot: current_slot,
             nft_count: new_nft_count,
@@ -156,4 +161,41 @@ mod tests {
         assert_eq!(checkpoints.get_past_votes(2550), 25);
         assert_eq!(checkpoints.get_past_votes(5000), 49);
     }
+    #[test]
+    fn test_update_checkpoint_drops_oldest_when_full() {
+        let mut checkpoints = VoterCheckpoints {
+            voter: Pubkey::default(),
+            checkpoints: vec![],
+            bump: 0,
+        };
+
+        // A stake or unstake in more distinct slots than the account holds
+        for i in 0..(MAX_VOTER_CHECKPOINTS as u64 + 10) {
+            checkpoints.update_checkpoint((i + 1) * 100, (i % 5) as u8).unwrap();
+        }
+
+        assert_eq!(checkpoints.checkpoints.len(), MAX_VOTER_CHECKPOINTS);
+        assert_eq!(checkpoints.checkpoints[0].slot, 1_100);
+        assert_eq!(checkpoints.get_past_votes(6_000), 4);
+        // Slots older than the retained history carry no voting power
+        assert_eq!(checkpoints.get_past_votes(1_000), 0);
+    }
+    #[test]
+    fn test_delegation_churn_past_capacity_keeps_latest_power() {
+        let mut delegate = VoterCheckpoints {
+            voter: Pubkey::default(),
+            checkpoints: vec![],
+            bump: 0,
+        };
+
+        // Votes delegated in and taken back out in alternating slots
+        for i in 0..(2 * MAX_VOTER_CHECKPOINTS as u64) {
+            let votes = if i % 2 == 0 { 8 } else { 3 };
+            delegate.update_checkpoint(1_000 + i, votes).unwrap();
+        }
+
+        assert_eq!(delegate.checkpoints.len(), MAX_VOTER_CHECKPOINTS);
+        assert_eq!(delegate.get_past_votes(1_000 + 2 * MAX_VOTER_CHECKPOINTS as u64), 3);
+        assert_eq!(delegate.get_past_votes(1_000 + 2 * MAX_VOTER_CHECKPOINTS as u64 - 2), 8);
+    }
 }
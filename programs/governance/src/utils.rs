@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+use crate::constant::TREASURY_SEED;
+use crate::errors::GovernanceError;
+use crate::states::{bps_threshold, AnswerOption, AnswerVoterRecord, GovernanceConfig, GovernanceItem};
+
+/// Grows an account to `new_len`, topping up its lamports from `payer` so it stays rent exempt.
+/// New bytes are zero-initialized.
+pub fn realloc_account<'info>(
+    account: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_len);
+    let lamports = account.lamports();
+    if rent_exempt_lamports > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program,
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_lamports - lamports,
+        )?;
+    }
+
+    account.resize(new_len)?;
+
+    Ok(())
+}
+
+/// Creates a program-owned PDA of `space` bytes, like Anchor's `init`.
+/// Lamports sent to the address beforehand are kept and only topped up to rent exemption.
+pub fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount {
+                    from: payer,
+                    to: account,
+                },
+                &[signer_seeds],
+            ),
+            rent_exempt_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if rent_exempt_lamports > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_lamports - lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign {
+                account_to_assign: account,
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// Staked NFTs behind a phase's votes. Items voted on before the NFT turnout was tracked only
+/// have the weighted tally, which no weighting curve makes larger than the NFTs behind it
+fn counted_turnout(nft_turnout: u64, weighted_votes: u64) -> u64 {
+    nft_turnout.max(weighted_votes)
+}
+
+/// Whether the NFTs behind the votes reach the bps quorum of `voting_supply`.
+/// Quorum is counted in NFTs, the unit of the supply, rather than in weighted votes:
+/// under a compressing curve full turnout would otherwise fall short of it
+pub fn quorum_reached(quorum_bps: u16, nft_turnout: u64, weighted_votes: u64, voting_supply: u64) -> bool {
+    counted_turnout(nft_turnout, weighted_votes) >= bps_threshold(voting_supply, quorum_bps)
+}
+
+/// Turnout checks shared by quest and decision finalization: the voter count against
+/// `min_total_vote` and the NFT turnout against the bps quorum of `voting_supply`
+pub fn require_quorum(
+    config: &GovernanceConfig,
+    total_voted: u64,
+    nft_turnout: u64,
+    weighted_votes: u64,
+    voting_supply: u64,
+) -> Result<()> {
+    require!(
+        total_voted >= config.min_total_vote,
+        GovernanceError::InsufficientVotes
+    );
+    require!(
+        quorum_reached(config.quorum_bps, nft_turnout, weighted_votes, voting_supply),
+        GovernanceError::QuorumNotReached
+    );
+    Ok(())
+}
+
+/// Whether `approve` carries a tally: a strict majority of the weighted votes over `reject`
+/// whose share of them, applied to the NFT turnout, meets the bps approval threshold of
+/// `voting_supply`. Under linear weighting that is simply the approving NFTs
+pub fn tally_passes(approval_bps: u16, approve: u64, reject: u64, nft_turnout: u64, voting_supply: u64) -> bool {
+    let cast = approve as u128 + reject as u128;
+    let turnout = counted_turnout(nft_turnout, approve.saturating_add(reject)) as u128;
+    approve > reject && approve as u128 * turnout >= bps_threshold(voting_supply, approval_bps) as u128 * cast
+}
+
+/// Answer options are 1-10 distinct keys; 0 is reserved for "no answer" in answer_result
+pub fn validate_answer_keys(answer_keys: &[u64]) -> Result<()> {
+    require!(
+        !answer_keys.is_empty()
+            && answer_keys.len() <= 10
+            && !answer_keys.contains(&0)
+            && answer_keys.iter().enumerate().all(|(i, key)| !answer_keys[..i].contains(key)),
+        GovernanceError::InvalidAnswerKeys
+    );
+    Ok(())
+}
+
+/// A voter's pro-rata share of a reward pool. Shares are rounded down except the one that
+/// completes `total_votes`, which takes what is left in the pool, so no dust stays behind
+pub fn pool_share(
+    pool_amount: u64,
+    pool_remaining: u64,
+    votes: u64,
+    claimed_votes: u64,
+    total_votes: u64,
+) -> Result<u64> {
+    let claimed = claimed_votes
+        .checked_add(votes)
+        .ok_or(GovernanceError::MathOverflow)?;
+    require!(total_votes > 0 && claimed <= total_votes, GovernanceError::InvalidParameter);
+    if claimed == total_votes {
+        return Ok(pool_remaining);
+    }
+    let share = pool_amount as u128 * votes as u128 / total_votes as u128;
+    Ok((share as u64).min(pool_remaining))
+}
+
+/// Pays a winning answer voter record and marks it rewarded. Items that pay from the reward
+/// pool give a pro-rata share of it, which needs the pool token account and the winning
+/// answer option, and count the record's votes as claimed; the rest pay the constant
+/// per-vote reward. Returns the amount and whether it comes from the pool
+pub fn pay_answer_reward(
+    config: &GovernanceConfig,
+    governance_item: &mut GovernanceItem,
+    record: &mut AnswerVoterRecord,
+    reward_pool_token_account: Option<&TokenAccount>,
+    winning_answer_option: Option<&AnswerOption>,
+) -> Result<(u64, bool)> {
+    let votes = record.vote_count as u64;
+    let reward = if governance_item.pays_reward_pool {
+        let (Some(reward_pool_token_account), Some(winning_answer_option)) =
+            (reward_pool_token_account, winning_answer_option)
+        else {
+            return err!(GovernanceError::RewardPoolAccountsRequired);
+        };
+        let reward = pool_share(
+            governance_item.reward_pool_amount,
+            reward_pool_token_account.amount,
+            votes,
+            governance_item.reward_pool_claimed_votes,
+            winning_answer_option.total_votes,
+        )?;
+        governance_item.reward_pool_claimed_votes += votes;
+        reward
+    } else {
+        votes
+            .checked_mul(config.constant_reward_token)
+            .ok_or(GovernanceError::MathOverflow)?
+    };
+    record.rewarded = true;
+    Ok((reward, governance_item.pays_reward_pool))
+}
+
+/// Transfers `amount` out of a token account owned by the treasury PDA
+pub fn transfer_from_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    treasury_pda: AccountInfo<'info>,
+    treasury_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: [&[u8]; 2] = [TREASURY_SEED, &[treasury_bump]];
+    let signer_seeds_refs: [&[&[u8]]; 1] = [&signer_seeds];
+    let cpi_accounts = Transfer {
+        from,
+        to,
+        authority: treasury_pda,
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program, cpi_accounts, &signer_seeds_refs),
+        amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_share_last_claim_takes_the_remainder() {
+        let pool = 100;
+        let mut remaining = pool;
+        let mut claimed = 0;
+        for votes in [1, 1, 1] {
+            let share = pool_share(pool, remaining, votes, claimed, 3).unwrap();
+            remaining -= share;
+            claimed += votes;
+        }
+        assert_eq!(remaining, 0);
+        assert_eq!(pool_share(pool, 34, 1, 2, 3).unwrap(), 34);
+        assert_eq!(pool_share(pool, 100, 1, 0, 3).unwrap(), 33);
+    }
+
+    #[test]
+    fn test_pool_share_rejects_claims_past_the_total() {
+        assert!(pool_share(100, 100, 2, 2, 3).is_err());
+        assert!(pool_share(100, 100, 1, 0, 0).is_err());
+    }
+}
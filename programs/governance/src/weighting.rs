@@ -0,0 +1,126 @@
+//! Vote weighting curves. Every vote instruction turns a checkpointed NFT count into
+//! counted votes through `VoteWeighting::weight`, so all phases use the same curve.
+//!
+//! Curves are evaluated in fixed point with `WEIGHT_PRECISION` fractional units and
+//! rounded half up to whole votes, which is what tallies and voter records store.
+
+//!
+//! Thresholds stay in NFTs: `quorum_bps` and `approval_bps` are checked against the staked
+//! NFTs behind the votes (see `utils::quorum_reached` and `utils::tally_passes`), so a
+//! compressing curve changes who wins a tally but not whether full turnout reaches quorum.
+
+use crate::constant::WEIGHT_PRECISION;
+use crate::states::VoteWeighting;
+
+impl VoteWeighting {
+    /// Votes counted for `nft_count` NFTs. Any holder of at least one NFT gets one vote.
+    pub fn weight(&self, nft_count: u8, max_votable_nft: u8) -> u8 {
+        if nft_count == 0 {
+            return 0;
+        }
+
+        let weight = match self {
+            VoteWeighting::CappedLinear => return nft_count.min(max_votable_nft),
+            VoteWeighting::Linear => return nft_count,
+            VoteWeighting::SquareRoot => sqrt_fixed(nft_count as u64 * WEIGHT_PRECISION),
+            VoteWeighting::Tiered => (nft_count.ilog2() as u64 + 1) * WEIGHT_PRECISION,
+        };
+
+        round_fixed(weight).max(1) as u8
+    }
+}
+
+/// Square root of a fixed-point value, in fixed point
+fn sqrt_fixed(value: u64) -> u64 {
+    isqrt(value as u128 * WEIGHT_PRECISION as u128) as u64
+}
+
+/// Integer square root (Newton's method), rounded down
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Rounds a fixed-point value half up to a whole number
+fn round_fixed(value: u64) -> u64 {
+    (value + WEIGHT_PRECISION / 2) / WEIGHT_PRECISION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{quorum_reached, tally_passes};
+
+    #[test]
+    fn test_linear_curves() {
+        assert_eq!(VoteWeighting::CappedLinear.weight(3, 5), 3);
+        assert_eq!(VoteWeighting::CappedLinear.weight(9, 5), 5);
+        assert_eq!(VoteWeighting::Linear.weight(9, 5), 9);
+        assert_eq!(VoteWeighting::Linear.weight(u8::MAX, 5), u8::MAX);
+    }
+
+    #[test]
+    fn test_square_root_rounds_to_nearest() {
+        let weights: Vec<u8> = [1, 2, 3, 4, 5, 6, 7, 9, 100, 255]
+            .iter()
+            .map(|&count| VoteWeighting::SquareRoot.weight(count, 5))
+            .collect();
+        // sqrt: 1, 1.41, 1.73, 2, 2.24, 2.45, 2.65, 3, 10, 15.97
+        assert_eq!(weights, vec![1, 1, 2, 2, 2, 2, 3, 3, 10, 16]);
+    }
+
+    #[test]
+    fn test_tiered_adds_a_vote_per_doubling() {
+        let weights: Vec<u8> = [1, 2, 3, 4, 7, 8, 255]
+            .iter()
+            .map(|&count| VoteWeighting::Tiered.weight(count, 5))
+            .collect();
+        assert_eq!(weights, vec![1, 2, 2, 3, 3, 4, 8]);
+    }
+
+    #[test]
+    fn test_thresholds_count_nfts_under_square_root() {
+        let supply = 100;
+        let whale = VoteWeighting::SquareRoot.weight(100, 5);
+        assert_eq!(whale, 10);
+        // Full turnout by one holder falls short of a 20% quorum in weighted votes alone
+        assert!(!quorum_reached(2_000, 0, whale as u64, supply));
+        assert!(quorum_reached(2_000, 100, whale as u64, supply));
+        assert!(tally_passes(2_000, whale as u64, 0, 100, supply));
+
+        // One holder of 64 NFTs against 36 single-NFT holders: 8 weighted votes to 36.
+        // The holders' 82% weighted share of the 100 NFT turnout meets 20% but not 90%
+        let holder = VoteWeighting::SquareRoot.weight(64, 5) as u64;
+        assert!(!tally_passes(2_000, holder, 36, 100, supply));
+        assert!(tally_passes(2_000, 36, holder, 100, supply));
+        assert!(!tally_passes(9_000, 36, holder, 100, supply));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX as u128), u32::MAX as u128);
+    }
+
+    #[test]
+    fn test_no_nfts_no_votes() {
+        for weighting in [
+            VoteWeighting::CappedLinear,
+            VoteWeighting::Linear,
+            VoteWeighting::SquareRoot,
+            VoteWeighting::Tiered,
+        ] {
+            assert_eq!(weighting.weight(0, 5), 0);
+        }
+    }
+}